@@ -0,0 +1,191 @@
+//! Simple calendar dates.
+
+use std::error::Error;
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A `YYYY-MM-DD` date in the proleptic Gregorian calendar.
+///
+/// This is only as precise as release schedules need, and does not carry a
+/// time of day or a time zone.
+#[derive(Clone, Copy, Debug, Default, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct Date {
+    /// The year, such as `2018`.
+    pub year: u16,
+    /// The month, from `1` to `12`.
+    pub month: u8,
+    /// The day of the month, from `1` to `31`.
+    pub day: u8,
+}
+
+impl From<(u16, u8, u8)> for Date {
+    #[inline]
+    fn from((year, month, day): (u16, u8, u8)) -> Self {
+        Date { year, month, day }
+    }
+}
+
+impl From<Date> for (u16, u8, u8) {
+    #[inline]
+    fn from(Date { year, month, day }: Date) -> Self {
+        (year, month, day)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = ParseDateError;
+
+    fn from_str(s: &str) -> Result<Date, ParseDateError> {
+        use self::ParseDateError::*;
+
+        let mut iter = s.trim().split('-');
+        let (year, month, day) = match (iter.next(), iter.next(), iter.next()) {
+            (Some(year), Some(month), Some(day)) => (year, month, day),
+            _ => return Err(Format),
+        };
+        if iter.next().is_some() {
+            return Err(Format);
+        }
+
+        let date = Date {
+            year: year.parse().map_err(Int)?,
+            month: month.parse().map_err(Int)?,
+            day: day.parse().map_err(Int)?,
+        };
+        if date.month < 1 || date.month > 12 {
+            return Err(OutOfRange);
+        }
+        if date.day < 1 || date.day > days_in_month(date.year, date.month) {
+            return Err(OutOfRange);
+        }
+        Ok(date)
+    }
+}
+
+impl Date {
+    /// Creates a new instance from the three values.
+    #[inline]
+    pub fn new(year: u16, month: u8, day: u8) -> Date {
+        Date { year, month, day }
+    }
+
+    /// Converts the date string formatted as `YYYY-MM-DD`.
+    #[inline]
+    pub fn parse(date: &str) -> Result<Date, ParseDateError> {
+        date.parse()
+    }
+
+    /// Converts the date string formatted as `YYYYMMDD`, as used for the
+    /// snapshots of rolling releases.
+    pub fn parse_compact(date: &str) -> Result<Date, ParseDateError> {
+        let date = date.trim();
+        if date.len() != 8 || !date.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseDateError::Format);
+        }
+        format!("{}-{}-{}", &date[..4], &date[4..6], &date[6..]).parse()
+    }
+
+    /// Returns the current date in UTC according to the system clock.
+    pub fn today() -> Date {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+        Date::from_days(secs / 86_400)
+    }
+
+    /// Returns the date that is `days` after 1970-01-01.
+    fn from_days(days: u64) -> Date {
+        // Howard Hinnant's `civil_from_days`, shifted so that years begin in
+        // March and leap days fall at the end
+        let days = days + 719_468;
+        let era = days / 146_097;
+        let day_of_era = days % 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524
+            - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4
+            - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+        Date::new(year as u16, month as u8, day as u8)
+    }
+}
+
+/// Returns the number of days in `month` of `year`.
+// `is_multiple_of` is newer than the minimum supported Rust version
+#[allow(clippy::manual_is_multiple_of)]
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// An error returned when parsing a date string fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseDateError {
+    /// Failed to parse an integer value.
+    Int(ParseIntError),
+    /// Parse input was not formatted as `YYYY-MM-DD`.
+    Format,
+    /// The month or day is not within the calendar.
+    OutOfRange,
+}
+
+impl fmt::Display for ParseDateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseDateError::Int(error) => error.fmt(f),
+            ParseDateError::Format => f.write_str("expected a YYYY-MM-DD date"),
+            ParseDateError::OutOfRange => f.write_str("date is out of range"),
+        }
+    }
+}
+
+impl Error for ParseDateError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(Date::parse("2018-04-26"), Ok(Date::new(2018, 4, 26)));
+        assert_eq!(Date::parse("2024-02-29"), Ok(Date::new(2024, 2, 29)));
+        assert_eq!(Date::parse("2018-04-26").unwrap().to_string(), "2018-04-26");
+
+        assert_eq!(Date::parse("2023-02-29"), Err(ParseDateError::OutOfRange));
+        assert_eq!(Date::parse("2018-13-01"), Err(ParseDateError::OutOfRange));
+        assert_eq!(Date::parse("2018-04"), Err(ParseDateError::Format));
+        assert_eq!(Date::parse("2018-04-26-1"), Err(ParseDateError::Format));
+        assert!(Date::parse("2018-04-xx").is_err());
+
+        assert_eq!(Date::parse_compact("20240101"), Ok(Date::new(2024, 1, 1)));
+        assert_eq!(Date::parse_compact("20241301"), Err(ParseDateError::OutOfRange));
+        assert_eq!(Date::parse_compact("2024-01-01"), Err(ParseDateError::Format));
+    }
+
+    #[test]
+    fn from_days() {
+        assert_eq!(Date::from_days(0), Date::new(1970, 1, 1));
+        assert_eq!(Date::from_days(11_016), Date::new(2000, 2, 29));
+        assert_eq!(Date::from_days(17_647), Date::new(2018, 4, 26));
+        assert!(Date::today() > Date::new(2018, 4, 26));
+    }
+}
@@ -0,0 +1,102 @@
+//! Release dates and support windows of operating system releases.
+
+use date::Date;
+
+/// When a release came out and how long it is supported for.
+///
+/// Dates are as published by the vendor and are inclusive: a release is still
+/// supported on the day its support ends.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Lifecycle {
+    /// The date of the first general availability release.
+    pub released: Date,
+    /// The end of standard support, such as Windows mainstream support or the
+    /// end of regular security updates, if announced.
+    pub support_end: Option<Date>,
+    /// The end of any extended support past `support_end`, such as Ubuntu
+    /// ESM, Debian LTS or ELTS, final macOS security updates or Windows
+    /// extended support.
+    pub extended_end: Option<Date>,
+}
+
+impl Lifecycle {
+    /// Returns the support status of the release on the date `on`.
+    ///
+    /// A release whose end of standard support is not announced is supported
+    /// until the end of any extended support.
+    pub fn status(&self, on: Date) -> SupportStatus {
+        let within = |end: Option<Date>| end.map(|end| on <= end);
+        let standard = within(self.support_end).or_else(|| within(self.extended_end));
+
+        if on < self.released {
+            SupportStatus::Unreleased
+        } else if standard != Some(false) {
+            SupportStatus::Supported
+        } else if within(self.extended_end) == Some(true) {
+            SupportStatus::Extended
+        } else {
+            SupportStatus::EndOfLife
+        }
+    }
+}
+
+/// The support status of a release on some date.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SupportStatus {
+    /// The release was not yet available.
+    Unreleased,
+    /// The release was within standard support.
+    Supported,
+    /// The release was past standard support but within extended support.
+    Extended,
+    /// The release was no longer supported.
+    EndOfLife,
+}
+
+impl SupportStatus {
+    /// Returns whether the release receives updates of any kind.
+    #[inline]
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn is_supported(self) -> bool {
+        match self {
+            SupportStatus::Supported | SupportStatus::Extended => true,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status() {
+        let lifecycle = Lifecycle {
+            released: Date::new(2018, 4, 26),
+            support_end: Some(Date::new(2023, 5, 31)),
+            extended_end: Some(Date::new(2028, 4, 1)),
+        };
+        let pairs = [
+            ((2018, 4, 25), SupportStatus::Unreleased),
+            ((2018, 4, 26), SupportStatus::Supported),
+            ((2023, 5, 31), SupportStatus::Supported),
+            ((2023, 6, 1),  SupportStatus::Extended),
+            ((2028, 4, 1),  SupportStatus::Extended),
+            ((2028, 4, 2),  SupportStatus::EndOfLife),
+        ];
+        for &(date, status) in &pairs {
+            assert_eq!(lifecycle.status(date.into()), status, "{:?}", date);
+        }
+
+        let current = Lifecycle { support_end: None, extended_end: None, ..lifecycle };
+        assert_eq!(current.status(Date::new(2030, 1, 1)), SupportStatus::Supported);
+
+        let ended = Lifecycle { extended_end: None, ..lifecycle };
+        assert_eq!(ended.status(Date::new(2023, 6, 1)), SupportStatus::EndOfLife);
+
+        let unannounced = Lifecycle { support_end: None, ..lifecycle };
+        assert_eq!(unannounced.status(Date::new(2028, 4, 1)), SupportStatus::Supported);
+        assert_eq!(unannounced.status(Date::new(2028, 4, 2)), SupportStatus::EndOfLife);
+        assert!(!SupportStatus::EndOfLife.is_supported());
+    }
+}
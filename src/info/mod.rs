@@ -1,66 +1,315 @@
 //! Utilities for querying OS information, such as version, name, and other
 //! things.
 
-use version::OsVersion;
+use std::path::PathBuf;
 
+use date::Date;
+use version::{KernelVersion, OsVersion, Version};
+
+pub mod lifecycle;
 pub mod os;
+pub mod release;
+
+pub use self::lifecycle::{Lifecycle, SupportStatus};
+pub use self::release::{
+    KnownRelease,
+    ParseReleaseError,
+    Release,
+    ReleaseIter,
+    UnknownRelease,
+};
 
 /// Metadata for a specific operating system.
 ///
 /// Information for each OS in non-exhaustive. Fields may be added later and it
 /// won't be considered breaking backwards compatibility.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum OsMeta {
     /// Apple macOS.
-    #[cfg(target_os = "macos")]
     MacOs {
-        /// The macOS release name, if known.
-        release: Option<os::OsRelease>
+        /// The macOS release, if found.
+        release: Option<Release<os::macos::OsRelease>>,
     },
 
     /// Microsoft Windows.
-    #[cfg(target_os = "windows")]
     Windows {
-        /// The Windows release name, if known.
-        release: Option<os::OsRelease>
+        /// The Windows release, if found.
+        release: Option<Release<os::windows::OsRelease>>,
     },
 
     /// Debian Linux.
-    #[cfg(target_os = "linux")]
     Debian {
-        /// The Debian release name, if known.
-        release: Option<os::debian::OsRelease>
+        /// The Debian release, if found.
+        ///
+        /// For the testing and unstable suites, this is the release in
+        /// development.
+        release: Option<Release<os::linux::debian::OsRelease>>,
+        /// The point release from `/etc/debian_version`, such as `12.5`.
+        point_release: Option<Version>,
+        /// The suite that the host follows, if found.
+        suite: Option<os::linux::debian::Suite>,
     },
 
-    /// Ubuntu Linux.
-    #[cfg(target_os = "linux")]
+    /// Ubuntu Linux, including Ubuntu Core.
     Ubuntu {
-        /// The Ubuntu release name, if known.
-        release: Option<os::ubuntu::OsRelease>
+        /// The Ubuntu release, if found, which for Ubuntu Core is the LTS
+        /// release it is built from.
+        release: Option<Release<os::linux::ubuntu::OsRelease>>,
+        /// The point release from the `os-release` `VERSION`, such as
+        /// `18.04.6`.
+        point_release: Option<Version>,
+    },
+
+    /// A Linux distribution derived from Debian or Ubuntu, such as Linux Mint
+    /// or Raspberry Pi OS.
+    Derivative {
+        /// The derivative, if known to this crate.
+        derivative: Option<os::linux::Derivative>,
+        /// The lower-case identifier of the derivative, such as `linuxmint`.
+        id: String,
+        /// The name of the derivative, such as `Linux Mint`.
+        name: Option<String>,
+        /// The derivative's own version, such as `21.3`.
+        version: Option<Version>,
+        /// The derivative's own codename, such as `virginia`.
+        codename: Option<String>,
+        /// The Debian or Ubuntu release that the derivative is based on.
+        base: os::linux::Base,
+    },
+
+    /// Fedora Linux.
+    Fedora {
+        /// The Fedora release, if found.
+        release: Option<Release<os::linux::fedora::OsRelease>>,
+    },
+
+    /// Red Hat Enterprise Linux or a distribution rebuilt from it, such as
+    /// CentOS or Rocky Linux.
+    Rhel {
+        /// The distribution, which shares its release with RHEL.
+        distro: os::linux::rhel::Distro,
+        /// The RHEL release, if found.
+        release: Option<Release<os::linux::rhel::OsRelease>>,
+        /// The minor release, such as `8.9` or `7.6.1810`, which CentOS
+        /// Stream lacks.
+        point_release: Option<Version>,
+    },
+
+    /// Amazon Linux.
+    AmazonLinux {
+        /// The Amazon Linux release, if found.
+        release: Option<Release<os::linux::amazon::OsRelease>>,
+    },
+
+    /// openSUSE or SUSE Linux Enterprise.
+    Suse {
+        /// The SUSE distribution.
+        distro: os::linux::suse::Distro,
+        /// The version, such as `15.5`, `15-SP5` or the Tumbleweed snapshot
+        /// `20240101`.
+        version: Option<os::linux::suse::SuseVersion>,
+        /// The SUSE Linux Enterprise release, or the one that openSUSE Leap
+        /// is built from, if found.
+        release: Option<Release<os::linux::suse::OsRelease>>,
+    },
+
+    /// Alpine Linux.
+    Alpine {
+        /// The Alpine Linux branch, if found.
+        ///
+        /// For the edge branch, this is the release in development.
+        release: Option<Release<os::linux::alpine::OsRelease>>,
+        /// The point release from `/etc/alpine-release`, such as `3.19.1`.
+        point_release: Option<Version>,
+        /// Whether the host follows the edge development branch.
+        edge: bool,
+    },
+
+    /// A rolling release Linux distribution without numbered releases, such
+    /// as Arch Linux.
+    Rolling {
+        /// The distribution.
+        distro: os::linux::rolling::Distro,
+        /// The snapshot or configuration that the host follows.
+        snapshot: os::linux::rolling::Snapshot,
+    },
+
+    /// NixOS, which keeps software in the Nix store rather than at the paths
+    /// of the Filesystem Hierarchy Standard.
+    NixOs {
+        /// The NixOS release, if found.
+        release: Option<Release<os::linux::nixos::OsRelease>>,
+        /// The full version, such as `23.11.20240101.abcdef0`.
+        version: Option<os::linux::nixos::NixVersion>,
+        /// The number of the current system generation, from
+        /// `/nix/var/nix/profiles/system`.
+        generation: Option<u64>,
+        /// The channel that `root` subscribes to as `nixos`, such as
+        /// `nixos-23.11`, if the system is not built from a flake.
+        channel: Option<String>,
+    },
+
+    /// Android, either as the target or as the host of a Linux program run
+    /// within Termux.
+    Android {
+        /// The Android release, if found.
+        release: Option<Release<os::android::OsRelease>>,
+        /// The API level, such as 34.
+        api_level: Option<u64>,
+        /// The date of the security patch level, such as `2024-01-05`.
+        security_patch: Option<Date>,
+        /// The prefix of the Termux installation, such as
+        /// `/data/data/com.termux/files/usr`, from `$PREFIX` or else the
+        /// primary user's installation, if found.
+        termux_prefix: Option<PathBuf>,
+    },
+
+    /// Google ChromeOS.
+    ChromeOs {
+        /// The milestone, such as 120, shared with the version of Chrome.
+        milestone: Option<u64>,
+        /// The platform version, such as `15662.76.0`.
+        platform_version: Option<Version>,
+        /// The release channel that the device follows.
+        channel: Option<os::linux::chromeos::Channel>,
+        /// The board that the image is built for, such as `octopus`.
+        board: Option<String>,
     },
 
     /// Some unknown Linux operating system.
-    #[cfg(target_os = "linux")]
-    UnknownLinux {},
+    UnknownLinux {
+        /// The `ID` field of the `os-release` file, if found.
+        id: Option<String>,
+        /// The `NAME` field of the `os-release` file, if found.
+        name: Option<String>,
+    },
+
+    /// Some operating system that is not supported by this crate.
+    Unknown {
+        /// The name of the operating system, as in
+        /// [`std::env::consts::OS`](https://doc.rust-lang.org/std/env/consts/constant.OS.html).
+        name: &'static str,
+    },
 }
 
 impl OsMeta {
     /// Returns the string representation of the operating system's release
     /// version name, if one is known.
-    pub fn release_name(&self) -> Option<&'static str> {
-        #[cfg(target_os = "linux")]
+    ///
+    /// For a release that is not known to this crate, this is the codename
+    /// reported by the host. For a derivative, this is the name of its base
+    /// release.
+    pub fn release_name(&self) -> Option<&str> {
+        match self {
+            OsMeta::MacOs { release, .. } => release.as_ref()?.name(),
+            OsMeta::Windows { release, .. } => release.as_ref()?.name(),
+            OsMeta::Ubuntu { release, .. } => release.as_ref()?.name(),
+            OsMeta::Debian { release, .. } => release.as_ref()?.name(),
+            OsMeta::Derivative { base, .. } => base.release_name(),
+            OsMeta::Fedora { release, .. } => release.as_ref()?.name(),
+            OsMeta::Rhel { release, .. } => release.as_ref()?.name(),
+            OsMeta::AmazonLinux { release, .. } => release.as_ref()?.name(),
+            OsMeta::Suse { release, .. } => release.as_ref()?.name(),
+            OsMeta::Alpine { release, .. } => release.as_ref()?.name(),
+            OsMeta::NixOs { release, .. } => release.as_ref()?.name(),
+            OsMeta::Android { release, .. } => release.as_ref()?.name(),
+            OsMeta::ChromeOs { .. } |
+            OsMeta::Rolling { .. } |
+            OsMeta::UnknownLinux { .. } |
+            OsMeta::Unknown { .. } => None,
+        }
+    }
+
+    /// Returns the lifecycle of the operating system's release, if known.
+    ///
+    /// This is the lifecycle of the distribution rather than of the release it
+    /// shares, such as the earlier end of CentOS Linux 8 compared to RHEL 8,
+    /// or of openSUSE Leap compared to the SUSE Linux Enterprise service pack
+    /// it is built from.
+    /// Derivatives have their own support schedules and so have none.
+    pub fn lifecycle(&self) -> Option<Lifecycle> {
+        match self {
+            OsMeta::MacOs { release, .. } => release.as_ref()?.lifecycle(),
+            OsMeta::Windows { release, .. } => release.as_ref()?.lifecycle(),
+            OsMeta::Ubuntu { release, .. } => release.as_ref()?.lifecycle(),
+            OsMeta::Debian { release, .. } => release.as_ref()?.lifecycle(),
+            OsMeta::Fedora { release, .. } => release.as_ref()?.lifecycle(),
+            OsMeta::Rhel { distro, release, .. } => match release.as_ref()? {
+                Release::Known(release) => Some(distro.lifecycle(*release)),
+                release => release.lifecycle(),
+            },
+            OsMeta::AmazonLinux { release, .. } => release.as_ref()?.lifecycle(),
+            OsMeta::Suse { distro, release, .. } => match release.as_ref()? {
+                Release::Known(release) => Some(distro.lifecycle(*release)),
+                release => release.lifecycle(),
+            },
+            OsMeta::Alpine { release, .. } => release.as_ref()?.lifecycle(),
+            OsMeta::NixOs { release, .. } => release.as_ref()?.lifecycle(),
+            OsMeta::Android { release, .. } => release.as_ref()?.lifecycle(),
+            OsMeta::Derivative { .. } |
+            OsMeta::ChromeOs { .. } |
+            OsMeta::Rolling { .. } |
+            OsMeta::UnknownLinux { .. } |
+            OsMeta::Unknown { .. } => None,
+        }
+    }
+
+    /// Returns the support status of the operating system's release on the
+    /// date `on`, if its lifecycle or the end of its support is known.
+    pub fn support_status(&self, on: Date) -> Option<SupportStatus> {
         match self {
-            OsMeta::Ubuntu { release, .. } => release.map(Into::into),
-            OsMeta::Debian { release, .. } => release.map(Into::into),
-            OsMeta::UnknownLinux { .. } => None,
+            OsMeta::Fedora { release: Some(release) } => release.support_status(on),
+            meta => meta.lifecycle().map(|lifecycle| lifecycle.status(on)),
         }
+    }
+
+    /// Returns whether the operating system is a rolling release rather than
+    /// a numbered release, as with Arch Linux, openSUSE Tumbleweed, Debian
+    /// testing and unstable, and the Alpine Linux edge branch.
+    // `matches!` requires Rust 1.42
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn is_rolling(&self) -> bool {
+        use self::os::linux::{debian::Suite, suse::Distro};
 
-        #[cfg(not(target_os = "linux"))]
         match self {
-            #[cfg(target_os = "macos")]
-            OsMeta::MacOs { release, .. } => release.map(Into::into),
-            #[cfg(target_os = "windows")]
-            OsMeta::Windows { release, .. } => release.map(Into::into),
+            OsMeta::Rolling { .. } |
+            OsMeta::Suse { distro: Distro::Tumbleweed, .. } |
+            OsMeta::Debian { suite: Some(Suite::Testing), .. } |
+            OsMeta::Debian { suite: Some(Suite::Unstable), .. } |
+            OsMeta::Alpine { edge: true, .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Returns whether the operating system is a Linux distribution with the
+    /// paths of the Filesystem Hierarchy Standard, such as `/usr/lib`.
+    ///
+    /// This is `false` for NixOS, where software is installed to the Nix
+    /// store, and for operating systems other than Linux.
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn has_fhs(&self) -> bool {
+        match self {
+            OsMeta::MacOs { .. } |
+            OsMeta::Windows { .. } |
+            OsMeta::NixOs { .. } |
+            OsMeta::Android { .. } |
+            OsMeta::Unknown { .. } => false,
+            _ => true,
+        }
+    }
+
+    /// Returns the date of the rolling release snapshot that the host is at,
+    /// if known, such as the openSUSE Tumbleweed `VERSION_ID`.
+    pub fn snapshot_date(&self) -> Option<Date> {
+        use self::os::linux::suse::SuseVersion;
+
+        match self {
+            OsMeta::Rolling { snapshot, .. } => snapshot.date,
+            OsMeta::Suse { version: Some(SuseVersion::Snapshot(date)), .. } => {
+                Some(*date)
+            },
+            _ => None,
         }
     }
 }
@@ -72,10 +321,21 @@ pub struct OsInfo {
     pub meta: OsMeta,
     /// The operating system version.
     pub version: Option<OsVersion>,
+    /// The version of the running kernel.
+    pub kernel: Option<KernelVersion>,
+    /// How the system is immutable, if `/usr` is read-only and software must
+    /// be installed by other means.
+    pub immutable: Option<os::linux::Immutable>,
+    /// The operating system that the host runs within, such as ChromeOS for
+    /// a Crostini container.
+    pub guest: Option<os::linux::Guest>,
 }
 
 impl OsInfo {
     /// Queries information about the host operating system.
+    ///
+    /// On platforms that are not supported by this crate, `meta` is
+    /// [`OsMeta::Unknown`](enum.OsMeta.html#variant.Unknown).
     pub fn get() -> OsInfo {
         os::get_info()
     }
@@ -96,7 +356,37 @@ mod tests {
                 OsMeta::MacOs { release, .. } => {
                     assert!(release.is_some());
                 },
+                meta => panic!("unexpected {:?}", meta),
+            }
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            assert!(info.version.is_some());
+            match info.meta {
+                OsMeta::Windows { release, .. } => {
+                    assert!(release.is_some());
+                },
+                meta => panic!("unexpected {:?}", meta),
             }
         }
+
+        #[cfg(not(any(
+            target_os = "macos",
+            target_os = "windows",
+            target_os = "linux",
+            target_os = "android",
+        )))]
+        assert_eq!(info.meta, OsMeta::Unknown { name: std::env::consts::OS });
+
+        // Rolling releases have no version, so only the kernel is certain
+        #[cfg(target_os = "linux")]
+        assert!(info.kernel.is_some());
+
+        #[cfg(target_os = "android")]
+        match info.meta {
+            OsMeta::Android { api_level, .. } => assert!(api_level.is_some()),
+            meta => panic!("unexpected {:?}", meta),
+        }
     }
 }
@@ -0,0 +1,246 @@
+//! Utilities specific to Android.
+
+use date::Date;
+use info::KnownRelease;
+use version::Version;
+
+releases! {
+    /// The release name of a known Android version.
+    ///
+    /// Codenames are the lower-case dessert names used within Google, such as
+    /// `upside_down_cake` for Android 14. Standard support ends with the last
+    /// monthly Android Security Bulletin to cover the release.
+    pub enum OsRelease: "Android" {
+        /// Android 5 (Lollipop).
+        Lollipop {
+            version: (5, _),
+            name: "Lollipop",
+            codename: "lollipop",
+            lifecycle: [(2014, 11, 12), (2017, 12, 01), -],
+            api_level: 21,
+        }
+
+        /// Android 6 (Marshmallow).
+        Marshmallow {
+            version: (6, _),
+            name: "Marshmallow",
+            codename: "marshmallow",
+            lifecycle: [(2015, 10, 05), (2018, 08, 01), -],
+            api_level: 23,
+        }
+
+        /// Android 7 (Nougat).
+        Nougat {
+            version: (7, _),
+            name: "Nougat",
+            codename: "nougat",
+            lifecycle: [(2016, 08, 22), (2019, 10, 01), -],
+            api_level: 24,
+        }
+
+        /// Android 8 (Oreo).
+        Oreo {
+            version: (8, _),
+            name: "Oreo",
+            codename: "oreo",
+            lifecycle: [(2017, 08, 21), (2021, 10, 01), -],
+            api_level: 26,
+        }
+
+        /// Android 9 (Pie).
+        Pie {
+            version: (9, _),
+            name: "Pie",
+            codename: "pie",
+            lifecycle: [(2018, 08, 06), (2022, 01, 01), -],
+            api_level: 28,
+        }
+
+        /// Android 10.
+        Android10 {
+            version: (10, _),
+            name: "10",
+            codename: "quince_tart",
+            lifecycle: [(2019, 09, 03), (2023, 02, 01), -],
+            api_level: 29,
+        }
+
+        /// Android 11.
+        Android11 {
+            version: (11, _),
+            name: "11",
+            codename: "red_velvet_cake",
+            lifecycle: [(2020, 09, 08), (2024, 02, 01), -],
+            api_level: 30,
+        }
+
+        /// Android 12, including Android 12L.
+        Android12 {
+            version: (12, _),
+            name: "12",
+            codename: "snow_cone",
+            lifecycle: [(2021, 10, 04), (2025, 03, 01), -],
+            api_level: 31,
+        }
+
+        /// Android 13.
+        Android13 {
+            version: (13, _),
+            name: "13",
+            codename: "tiramisu",
+            lifecycle: [(2022, 08, 15), (2026, 03, 01), -],
+            api_level: 33,
+        }
+
+        /// Android 14.
+        Android14 {
+            version: (14, _),
+            name: "14",
+            codename: "upside_down_cake",
+            lifecycle: [(2023, 10, 04), -, -],
+            api_level: 34,
+        }
+
+        /// Android 15.
+        Android15 {
+            version: (15, _),
+            name: "15",
+            codename: "vanilla_ice_cream",
+            lifecycle: [(2024, 09, 03), -, -],
+            api_level: 35,
+        }
+
+        /// Android 16.
+        Android16 {
+            version: (16, _),
+            name: "16",
+            codename: "baklava",
+            lifecycle: [(2025, 06, 10), -, -],
+            api_level: 36,
+        }
+    }
+
+    struct Data {
+        api_level: u64,
+    }
+}
+
+impl OsRelease {
+    /// Returns the API level of the release's first version, such as 26 for
+    /// Android 8.0.
+    #[inline]
+    pub fn api_level(self) -> u64 {
+        self.data().api_level
+    }
+
+    /// Returns the release with `api_level`, such as Oreo for either 26 or
+    /// 27.
+    ///
+    /// API levels beyond that of the latest known release are not assumed to
+    /// belong to it.
+    pub fn from_api_level(api_level: u64) -> Option<OsRelease> {
+        ALL.iter()
+            .rev()
+            .find(|release| release.api_level() <= api_level)
+            .cloned()
+            .filter(|release| {
+                release.next().is_some() || release.api_level() == api_level
+            })
+    }
+}
+
+/// The build properties of an Android system, as found in
+/// `/system/build.prop`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BuildProp {
+    /// `ro.build.version.release`, such as `14` or `8.1.0`.
+    pub release: Option<Version>,
+    /// `ro.build.version.sdk`, the API level, such as 34.
+    pub api_level: Option<u64>,
+    /// `ro.build.version.security_patch`, the date of the security patch
+    /// level, such as `2024-01-05`.
+    pub security_patch: Option<Date>,
+}
+
+impl BuildProp {
+    /// Parses the `key=value` properties in `contents`, ignoring comments
+    /// and properties that are not recognized.
+    pub fn parse(contents: &str) -> BuildProp {
+        let mut build_prop = BuildProp::default();
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.starts_with('#') {
+                continue;
+            }
+            let mut parts = line.splitn(2, '=');
+            let (key, value) = match (parts.next(), parts.next()) {
+                (Some(key), Some(value)) => (key.trim(), value.trim()),
+                _ => continue,
+            };
+            match key {
+                "ro.build.version.release" => {
+                    build_prop.release = value.parse().ok();
+                },
+                "ro.build.version.sdk" => {
+                    build_prop.api_level = value.parse().ok();
+                },
+                "ro.build.version.security_patch" => {
+                    build_prop.security_patch = value.parse().ok();
+                },
+                _ => {},
+            }
+        }
+        build_prop
+    }
+
+    /// Returns the known release that the properties describe, by the
+    /// release version or else the API level.
+    pub fn os_release(&self) -> Option<OsRelease> {
+        self.release.and_then(OsRelease::new)
+            .or_else(|| self.api_level.and_then(OsRelease::from_api_level))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn api_levels() {
+        for &release in ALL {
+            assert_eq!(OsRelease::from_api_level(release.api_level()), Some(release));
+        }
+        assert_eq!(OsRelease::from_api_level(27), Some(OsRelease::Oreo));
+        assert_eq!(OsRelease::from_api_level(32), Some(OsRelease::Android12));
+        assert_eq!(OsRelease::from_api_level(19), None);
+        assert_eq!(OsRelease::from_api_level(99), None);
+    }
+
+    #[test]
+    fn lifecycles() {
+        use info::SupportStatus;
+
+        let on = Date::new(2024, 6, 1);
+        assert_eq!(OsRelease::Lollipop.support_status(on), SupportStatus::EndOfLife);
+        assert_eq!(OsRelease::Android11.support_status(on), SupportStatus::EndOfLife);
+        assert_eq!(OsRelease::Android12.support_status(on), SupportStatus::Supported);
+    }
+
+    #[test]
+    fn parse_build_prop() {
+        let contents = "\
+            # begin build properties\n\
+            ro.build.version.sdk=27\n\
+            ro.build.version.release=8.1.0\n\
+            ro.build.version.security_patch=2018-12-05\n\
+            ro.product.model=Pixel\n";
+        let build_prop = BuildProp::parse(contents);
+        assert_eq!(build_prop, BuildProp {
+            release: Some(Version::new(8, 1, 0)),
+            api_level: Some(27),
+            security_patch: Some(Date::new(2018, 12, 5)),
+        });
+        assert_eq!(build_prop.os_release(), Some(OsRelease::Oreo));
+        assert_eq!(BuildProp::parse("").os_release(), None);
+    }
+}
@@ -0,0 +1,163 @@
+//! Utilities specific to Alpine Linux.
+
+use version::Version;
+
+releases! {
+    /// The release name of a known Alpine Linux stable branch.
+    ///
+    /// Branches are referred to by number and have no codename. Standard
+    /// support ends with security fixes to the `main` repository, two years
+    /// after release.
+    pub enum OsRelease: "Alpine Linux" {
+        /// Alpine Linux 3.10.
+        V3_10 {
+            version: (3, 10),
+            name: "3.10",
+            codename: -,
+            lifecycle: [(2019, 06, 19), (2021, 05, 01), -],
+            musl: (1, 1, 22),
+            kernel: (4, 19, 0),
+        }
+
+        /// Alpine Linux 3.11.
+        V3_11 {
+            version: (3, 11),
+            name: "3.11",
+            codename: -,
+            lifecycle: [(2019, 12, 19), (2021, 11, 01), -],
+            musl: (1, 1, 24),
+            kernel: (5, 4, 0),
+        }
+
+        /// Alpine Linux 3.12.
+        V3_12 {
+            version: (3, 12),
+            name: "3.12",
+            codename: -,
+            lifecycle: [(2020, 05, 29), (2022, 05, 01), -],
+            musl: (1, 1, 24),
+            kernel: (5, 4, 0),
+        }
+
+        /// Alpine Linux 3.13.
+        V3_13 {
+            version: (3, 13),
+            name: "3.13",
+            codename: -,
+            lifecycle: [(2021, 01, 14), (2022, 11, 01), -],
+            musl: (1, 2, 2),
+            kernel: (5, 10, 0),
+        }
+
+        /// Alpine Linux 3.14.
+        V3_14 {
+            version: (3, 14),
+            name: "3.14",
+            codename: -,
+            lifecycle: [(2021, 06, 15), (2023, 05, 01), -],
+            musl: (1, 2, 2),
+            kernel: (5, 10, 0),
+        }
+
+        /// Alpine Linux 3.15.
+        V3_15 {
+            version: (3, 15),
+            name: "3.15",
+            codename: -,
+            lifecycle: [(2021, 11, 24), (2023, 11, 01), -],
+            musl: (1, 2, 2),
+            kernel: (5, 15, 0),
+        }
+
+        /// Alpine Linux 3.16.
+        V3_16 {
+            version: (3, 16),
+            name: "3.16",
+            codename: -,
+            lifecycle: [(2022, 05, 23), (2024, 05, 23), -],
+            musl: (1, 2, 3),
+            kernel: (5, 15, 0),
+        }
+
+        /// Alpine Linux 3.17.
+        V3_17 {
+            version: (3, 17),
+            name: "3.17",
+            codename: -,
+            lifecycle: [(2022, 11, 22), (2024, 11, 22), -],
+            musl: (1, 2, 3),
+            kernel: (5, 15, 0),
+        }
+
+        /// Alpine Linux 3.18.
+        V3_18 {
+            version: (3, 18),
+            name: "3.18",
+            codename: -,
+            lifecycle: [(2023, 05, 09), (2025, 05, 09), -],
+            musl: (1, 2, 4),
+            kernel: (6, 1, 0),
+        }
+
+        /// Alpine Linux 3.19.
+        V3_19 {
+            version: (3, 19),
+            name: "3.19",
+            codename: -,
+            lifecycle: [(2023, 12, 07), (2025, 11, 01), -],
+            musl: (1, 2, 4),
+            kernel: (6, 6, 0),
+        }
+
+        /// Alpine Linux 3.20.
+        V3_20 {
+            version: (3, 20),
+            name: "3.20",
+            codename: -,
+            lifecycle: [(2024, 05, 22), (2026, 04, 01), -],
+            musl: (1, 2, 5),
+            kernel: (6, 6, 0),
+        }
+
+        /// Alpine Linux 3.21.
+        V3_21 {
+            version: (3, 21),
+            name: "3.21",
+            codename: -,
+            lifecycle: [(2024, 12, 05), (2026, 11, 01), -],
+            musl: (1, 2, 5),
+            kernel: (6, 12, 0),
+        }
+
+        /// Alpine Linux 3.22.
+        V3_22 {
+            version: (3, 22),
+            name: "3.22",
+            codename: -,
+            lifecycle: [(2025, 05, 30), (2027, 05, 01), -],
+            musl: (1, 2, 5),
+            kernel: (6, 12, 0),
+        }
+    }
+
+    struct Data {
+        musl: (u64, u64, u64),
+        kernel: (u64, u64, u64),
+    }
+}
+
+impl OsRelease {
+    /// Returns the version of the musl C library that the branch shipped
+    /// with.
+    #[inline]
+    pub fn musl_version(self) -> Version {
+        self.data().musl.into()
+    }
+
+    /// Returns the kernel series of the `linux-lts` package that the branch
+    /// shipped with, such as `6.6` for Alpine Linux 3.19.
+    #[inline]
+    pub fn kernel_version(self) -> Version {
+        self.data().kernel.into()
+    }
+}
@@ -0,0 +1,51 @@
+//! Utilities specific to Amazon Linux.
+
+use super::rhel;
+use version::Version;
+
+releases! {
+    /// The release name of a known Amazon Linux version.
+    ///
+    /// Standard support ends with the end of regular security and bug fix
+    /// updates, and the extended end is the end of the maintenance phase.
+    pub enum OsRelease: "Amazon Linux" {
+        /// Amazon Linux 2.
+        AmazonLinux2 {
+            version: (2, _),
+            name: "2",
+            codename: "karoo",
+            lifecycle: [(2018, 06, 26), (2026, 06, 30), -],
+            glibc: (2, 26, 0),
+            rhel: rhel::OsRelease::Rhel7,
+        }
+
+        /// Amazon Linux 2023, based on Fedora and CentOS Stream 9.
+        AmazonLinux2023 {
+            version: (2023, _),
+            name: "2023",
+            codename: -,
+            lifecycle: [(2023, 03, 15), (2027, 06, 30), (2029, 06, 30)],
+            glibc: (2, 34, 0),
+            rhel: rhel::OsRelease::Rhel9,
+        }
+    }
+
+    struct Data {
+        glibc: (u64, u64, u64),
+        rhel: rhel::OsRelease,
+    }
+}
+
+impl OsRelease {
+    /// Returns the version of the GNU C Library that the release ships.
+    #[inline]
+    pub fn glibc_version(self) -> Version {
+        self.data().glibc.into()
+    }
+
+    /// Returns the RHEL release that the release is compatible with.
+    #[inline]
+    pub fn rhel(self) -> rhel::OsRelease {
+        self.data().rhel
+    }
+}
@@ -0,0 +1,61 @@
+//! Utilities specific to ChromeOS.
+
+/// The release channel that a ChromeOS device follows, from the
+/// `CHROMEOS_RELEASE_TRACK` in `/etc/lsb-release`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Channel {
+    /// The stable channel, updated with a new milestone every four weeks.
+    Stable,
+    /// The beta channel.
+    Beta,
+    /// The developer channel.
+    Dev,
+    /// The canary channel, updated daily.
+    Canary,
+    /// The long-term support channel, which stays on a milestone for six
+    /// months.
+    Lts,
+    /// The long-term support candidate channel.
+    Ltc,
+}
+
+impl Channel {
+    /// Returns the channel for `track`, such as `stable-channel`.
+    pub fn from_track(track: &str) -> Option<Channel> {
+        match track.trim().trim_end_matches("-channel") {
+            "stable" => Some(Channel::Stable),
+            "beta"   => Some(Channel::Beta),
+            "dev"    => Some(Channel::Dev),
+            "canary" => Some(Channel::Canary),
+            "lts"    => Some(Channel::Lts),
+            "ltc"    => Some(Channel::Ltc),
+            _ => None,
+        }
+    }
+}
+
+impl From<Channel> for &'static str {
+    fn from(channel: Channel) -> Self {
+        match channel {
+            Channel::Stable => "stable",
+            Channel::Beta   => "beta",
+            Channel::Dev    => "dev",
+            Channel::Canary => "canary",
+            Channel::Lts    => "lts",
+            Channel::Ltc    => "ltc",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracks() {
+        assert_eq!(Channel::from_track("stable-channel"), Some(Channel::Stable));
+        assert_eq!(Channel::from_track("ltc-channel"), Some(Channel::Ltc));
+        assert_eq!(Channel::from_track("testimage-channel"), None);
+    }
+}
@@ -1,120 +1,216 @@
 //! Utilities specific to Debian Linux.
 
-use version::Version;
-
-use self::OsRelease::*;
-
-/// The release name of a known Debian version.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
-pub enum OsRelease {
-    /// Buzz (Debian 1.1).
-    Buzz,
-    /// Rex (Debian 1.2).
-    Rex,
-    /// Bo (Debian 1.3).
-    Bo,
-    /// Hamm (Debian 2.0).
-    Hamm,
-    /// Slink (Debian 2.1).
-    Slink,
-    /// Potato (Debian 2.2).
-    Potato,
-    /// Woody (Debian 3.0).
-    Woody,
-    /// Sarge (Debian 3.1).
-    Sarge,
-    /// Etch (Debian 4.0).
-    Etch,
-    /// Lenny (Debian 5.0).
-    Lenny,
-    /// Squeeze (Debian 6.0).
-    Squeeze,
-    /// Wheezy (Debian 7.0).
-    Wheezy,
-    /// Jessie (Debian 8.0).
-    Jessie,
-    /// Stretch (Debian 9.0).
-    Stretch,
-    // We assume this value will never be used, so `unreachable_unchecked()` is
-    // fine to use
-    #[doc(hidden)]
-    _NonExhaustive,
-}
+use date::Date;
+use info::KnownRelease;
+
+releases! {
+    /// The release name of a known Debian version.
+    ///
+    /// Point releases only change the minor version as of Wheezy (7.x). The
+    /// extended end of a release's lifecycle is the end of Extended LTS
+    /// (ELTS), or else of LTS.
+    pub enum OsRelease: "Debian" {
+        /// Buzz (Debian 1.1).
+        Buzz {
+            version: (1, 1),
+            name: "Buzz",
+            codename: "buzz",
+            lifecycle: [(1996, 06, 17), (1997, 06, 05), -],
+            lts_end: None,
+        }
+
+        /// Rex (Debian 1.2).
+        Rex {
+            version: (1, 2),
+            name: "Rex",
+            codename: "rex",
+            lifecycle: [(1996, 12, 12), (1998, 06, 05), -],
+            lts_end: None,
+        }
+
+        /// Bo (Debian 1.3).
+        Bo {
+            version: (1, 3),
+            name: "Bo",
+            codename: "bo",
+            lifecycle: [(1997, 06, 05), (1999, 03, 09), -],
+            lts_end: None,
+        }
+
+        /// Hamm (Debian 2.0).
+        Hamm {
+            version: (2, 0),
+            name: "Hamm",
+            codename: "hamm",
+            lifecycle: [(1998, 07, 24), (2000, 03, 09), -],
+            lts_end: None,
+        }
+
+        /// Slink (Debian 2.1).
+        Slink {
+            version: (2, 1),
+            name: "Slink",
+            codename: "slink",
+            lifecycle: [(1999, 03, 09), (2000, 10, 30), -],
+            lts_end: None,
+        }
+
+        /// Potato (Debian 2.2).
+        Potato {
+            version: (2, 2),
+            name: "Potato",
+            codename: "potato",
+            lifecycle: [(2000, 08, 15), (2003, 06, 30), -],
+            lts_end: None,
+        }
+
+        /// Woody (Debian 3.0).
+        Woody {
+            version: (3, 0),
+            name: "Woody",
+            codename: "woody",
+            lifecycle: [(2002, 07, 19), (2006, 06, 30), -],
+            lts_end: None,
+        }
+
+        /// Sarge (Debian 3.1).
+        Sarge {
+            version: (3, 1),
+            name: "Sarge",
+            codename: "sarge",
+            lifecycle: [(2005, 06, 06), (2008, 03, 31), -],
+            lts_end: None,
+        }
+
+        /// Etch (Debian 4.0).
+        Etch {
+            version: (4, 0),
+            name: "Etch",
+            codename: "etch",
+            lifecycle: [(2007, 04, 08), (2010, 02, 15), -],
+            lts_end: None,
+        }
+
+        /// Lenny (Debian 5.0).
+        Lenny {
+            version: (5, 0),
+            name: "Lenny",
+            codename: "lenny",
+            lifecycle: [(2009, 02, 14), (2012, 02, 06), -],
+            lts_end: None,
+        }
 
-impl<'a> From<OsRelease> for &'a str {
-    fn from(release: OsRelease) -> Self {
-        match release {
-            Buzz           => "Buzz",
-            Rex            => "Rex",
-            Bo             => "Bo",
-            Hamm           => "Hamm",
-            Slink          => "Slink",
-            Potato         => "Potato",
-            Woody          => "Woody",
-            Sarge          => "Sarge",
-            Etch           => "Etch",
-            Lenny          => "Lenny",
-            Squeeze        => "Squeeze",
-            Wheezy         => "Wheezy",
-            Jessie         => "Jessie",
-            Stretch        => "Stretch",
-            _NonExhaustive => unsafe { std::hint::unreachable_unchecked() },
+        /// Squeeze (Debian 6.0).
+        Squeeze {
+            version: (6, 0),
+            name: "Squeeze",
+            codename: "squeeze",
+            lifecycle: [(2011, 02, 06), (2014, 05, 31), (2016, 02, 29)],
+            lts_end: Some((2016, 02, 29)),
         }
+
+        /// Wheezy (Debian 7.0).
+        Wheezy {
+            version: (7, _),
+            name: "Wheezy",
+            codename: "wheezy",
+            lifecycle: [(2013, 05, 04), (2016, 04, 25), (2020, 06, 30)],
+            lts_end: Some((2018, 05, 31)),
+        }
+
+        /// Jessie (Debian 8.0).
+        Jessie {
+            version: (8, _),
+            name: "Jessie",
+            codename: "jessie",
+            lifecycle: [(2015, 04, 26), (2018, 06, 17), (2025, 06, 30)],
+            lts_end: Some((2020, 06, 30)),
+        }
+
+        /// Stretch (Debian 9.0).
+        Stretch {
+            version: (9, _),
+            name: "Stretch",
+            codename: "stretch",
+            lifecycle: [(2017, 06, 17), (2020, 07, 18), (2027, 06, 30)],
+            lts_end: Some((2022, 06, 30)),
+        }
+
+        /// Buster (Debian 10).
+        Buster {
+            version: (10, _),
+            name: "Buster",
+            codename: "buster",
+            lifecycle: [(2019, 07, 06), (2022, 09, 10), (2029, 06, 30)],
+            lts_end: Some((2024, 06, 30)),
+        }
+
+        /// Bullseye (Debian 11).
+        Bullseye {
+            version: (11, _),
+            name: "Bullseye",
+            codename: "bullseye",
+            lifecycle: [(2021, 08, 14), (2024, 08, 14), (2031, 06, 30)],
+            lts_end: Some((2026, 08, 31)),
+        }
+
+        /// Bookworm (Debian 12).
+        Bookworm {
+            version: (12, _),
+            name: "Bookworm",
+            codename: "bookworm",
+            lifecycle: [(2023, 06, 10), (2026, 07, 11), (2033, 06, 30)],
+            lts_end: Some((2028, 06, 30)),
+        }
+
+        /// Trixie (Debian 13).
+        Trixie {
+            version: (13, _),
+            name: "Trixie",
+            codename: "trixie",
+            lifecycle: [(2025, 08, 09), (2028, 08, 09), (2035, 06, 30)],
+            lts_end: Some((2030, 06, 30)),
+        }
+    }
+
+    struct Data {
+        lts_end: Option<(u16, u8, u8)>,
     }
 }
 
 impl OsRelease {
-    /// The minimum supported OS release.
-    pub const MIN: OsRelease = Buzz;
-
-    /// The most recent OS release.
-    pub const LATEST: OsRelease = Stretch;
-
-    // This might be a large table; monomorphize here
-    fn _new(Version { major, minor, .. }: Version) -> Option<Self> {
-        match (major, minor) {
-            (1, 1) => Some(Buzz),
-            (1, 2) => Some(Rex),
-            (1, 3) => Some(Bo),
-            (2, 0) => Some(Hamm),
-            (2, 1) => Some(Slink),
-            (2, 2) => Some(Potato),
-            (3, 0) => Some(Woody),
-            (3, 1) => Some(Sarge),
-            (4, 0) => Some(Etch),
-            (5, 0) => Some(Lenny),
-            (6, 0) => Some(Squeeze),
-            (7, 0) => Some(Wheezy),
-            (8, 0) => Some(Jessie),
-            (9, 0) => Some(Stretch),
-            _ => None,
-        }
+    /// Returns the end of Debian Long Term Support (LTS) for the release, if
+    /// it was covered.
+    #[inline]
+    pub fn lts_end(self) -> Option<Date> {
+        self.data().lts_end.map(Date::from)
     }
 
-    /// Returns the corresponding release for the Ubuntu version number.
-    pub fn new<V: Into<Version>>(version: V) -> Option<Self> {
-        Self::_new(version.into())
+    /// Returns the end of commercial Extended LTS (ELTS) for the release, if
+    /// it was covered.
+    pub fn elts_end(self) -> Option<Date> {
+        self.lifecycle().extended_end.filter(|&end| Some(end) != self.lts_end())
     }
 }
 
-impl From<OsRelease> for Version {
-    fn from(release: OsRelease) -> Version {
-        match release {
-            Buzz           => (1, 1),
-            Rex            => (1, 2),
-            Bo             => (1, 3),
-            Hamm           => (2, 0),
-            Slink          => (2, 1),
-            Potato         => (2, 2),
-            Woody          => (3, 0),
-            Sarge          => (3, 1),
-            Etch           => (4, 0),
-            Lenny          => (5, 0),
-            Squeeze        => (6, 0),
-            Wheezy         => (7, 0),
-            Jessie         => (8, 0),
-            Stretch        => (9, 0),
-            _NonExhaustive => unsafe { std::hint::unreachable_unchecked() },
-        }.into()
+/// The Debian suite that a host follows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Suite {
+    /// A numbered release and its point releases.
+    Stable,
+    /// The next release while in development, for which
+    /// `/etc/debian_version` reads like `trixie/sid`.
+    Testing,
+    /// The unstable development suite, also known as `sid`.
+    Unstable,
+}
+
+impl From<Suite> for &'static str {
+    fn from(suite: Suite) -> Self {
+        match suite {
+            Suite::Stable   => "stable",
+            Suite::Testing  => "testing",
+            Suite::Unstable => "unstable",
+        }
     }
 }
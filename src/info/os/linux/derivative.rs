@@ -0,0 +1,159 @@
+//! Distributions derived from Debian or Ubuntu.
+
+use info::{KnownRelease, Release};
+use super::{debian, ubuntu};
+
+/// A distribution derived from Debian or Ubuntu that this crate recognizes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Derivative {
+    /// Linux Mint, including Linux Mint Debian Edition (LMDE).
+    LinuxMint,
+    /// Pop!_OS, based on Ubuntu.
+    PopOs,
+    /// elementary OS, based on Ubuntu.
+    Elementary,
+    /// Zorin OS, based on Ubuntu.
+    Zorin,
+    /// KDE neon, based on Ubuntu LTS releases.
+    Neon,
+    /// Raspberry Pi OS, formerly Raspbian, based on Debian.
+    ///
+    /// Its 64-bit images report the `ID` of Debian and are told apart by
+    /// `/etc/rpi-issue`.
+    Raspbian,
+    /// Kali Linux, based on Debian testing.
+    Kali,
+    /// Devuan, based on Debian without systemd.
+    Devuan,
+    /// MX Linux, based on Debian.
+    ///
+    /// It reports the `ID` of Debian in `os-release` and describes itself in
+    /// `/etc/lsb-release`.
+    Mx,
+}
+
+impl Derivative {
+    /// Returns the derivative with the lower-case `os-release` `ID`, such as
+    /// `linuxmint`.
+    pub fn from_id(id: &str) -> Option<Derivative> {
+        match id {
+            "linuxmint"  => Some(Derivative::LinuxMint),
+            "pop"        => Some(Derivative::PopOs),
+            "elementary" => Some(Derivative::Elementary),
+            "zorin"      => Some(Derivative::Zorin),
+            "neon"       => Some(Derivative::Neon),
+            "raspbian"   => Some(Derivative::Raspbian),
+            "kali"       => Some(Derivative::Kali),
+            "devuan"     => Some(Derivative::Devuan),
+            "mx"         => Some(Derivative::Mx),
+            _ => None,
+        }
+    }
+
+    /// Returns the lower-case identifier of the derivative, as reported by
+    /// its `os-release` `ID` where it has its own.
+    pub fn id(self) -> &'static str {
+        match self {
+            Derivative::LinuxMint  => "linuxmint",
+            Derivative::PopOs      => "pop",
+            Derivative::Elementary => "elementary",
+            Derivative::Zorin      => "zorin",
+            Derivative::Neon       => "neon",
+            Derivative::Raspbian   => "raspbian",
+            Derivative::Kali       => "kali",
+            Derivative::Devuan     => "devuan",
+            Derivative::Mx         => "mx",
+        }
+    }
+}
+
+impl From<Derivative> for &'static str {
+    fn from(derivative: Derivative) -> Self {
+        match derivative {
+            Derivative::LinuxMint  => "Linux Mint",
+            Derivative::PopOs      => "Pop!_OS",
+            Derivative::Elementary => "elementary OS",
+            Derivative::Zorin      => "Zorin OS",
+            Derivative::Neon       => "KDE neon",
+            Derivative::Raspbian   => "Raspberry Pi OS",
+            Derivative::Kali       => "Kali Linux",
+            Derivative::Devuan     => "Devuan",
+            Derivative::Mx         => "MX Linux",
+        }
+    }
+}
+
+/// The release that a derivative is based on and binary-compatible with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Base {
+    /// A Debian release.
+    Debian {
+        /// The Debian release, if found.
+        release: Option<Release<debian::OsRelease>>,
+    },
+    /// An Ubuntu release.
+    Ubuntu {
+        /// The Ubuntu release, if found.
+        release: Option<Release<ubuntu::OsRelease>>,
+    },
+}
+
+impl Base {
+    /// Returns the name of the base operating system, such as `Ubuntu`.
+    pub fn os_name(&self) -> &'static str {
+        match self {
+            Base::Debian { .. } => debian::OsRelease::OS_NAME,
+            Base::Ubuntu { .. } => ubuntu::OsRelease::OS_NAME,
+        }
+    }
+
+    /// Returns the name of the base release, if found.
+    pub fn release_name(&self) -> Option<&str> {
+        match self {
+            Base::Debian { release } => release.as_ref()?.name(),
+            Base::Ubuntu { release } => release.as_ref()?.name(),
+        }
+    }
+}
+
+/// Returns the Debian release that the Devuan release with `codename` is
+/// based on, such as Bookworm for `daedalus`.
+pub fn devuan_base(codename: &str) -> Option<debian::OsRelease> {
+    use super::debian::OsRelease::*;
+
+    match codename {
+        "jessie"    => Some(Jessie),
+        "ascii"     => Some(Stretch),
+        "beowulf"   => Some(Buster),
+        "chimaera"  => Some(Bullseye),
+        "daedalus"  => Some(Bookworm),
+        "excalibur" => Some(Trixie),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids() {
+        let all = [
+            Derivative::LinuxMint,
+            Derivative::PopOs,
+            Derivative::Elementary,
+            Derivative::Zorin,
+            Derivative::Neon,
+            Derivative::Raspbian,
+            Derivative::Kali,
+            Derivative::Devuan,
+            Derivative::Mx,
+        ];
+        for &derivative in &all {
+            assert_eq!(Derivative::from_id(derivative.id()), Some(derivative));
+        }
+        assert_eq!(Derivative::from_id("ubuntu"), None);
+    }
+}
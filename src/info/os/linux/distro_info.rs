@@ -0,0 +1,292 @@
+//! Parsing of the release tables from
+//! [`distro-info-data`](https://salsa.debian.org/debian/distro-info-data),
+//! which Debian and Ubuntu install under `/usr/share/distro-info`.
+//!
+//! These tables are updated with the distribution and so know of releases
+//! that are newer than this crate.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use date::Date;
+use info::{KnownRelease, Lifecycle, Release, UnknownRelease};
+use version::Version;
+
+/// The directory containing a table for each distribution, such as
+/// `ubuntu.csv` and `debian.csv`.
+pub const DIR: &str = "/usr/share/distro-info";
+
+/// A release listed in a `distro-info-data` table.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DistroInfoRecord {
+    /// `version`: the version number, such as `18.04`, or `None` for suites
+    /// such as Debian `sid`.
+    pub version: Option<Version>,
+    /// Whether the version is marked as `LTS`.
+    pub is_lts: bool,
+    /// `codename`: the display name of the release, such as `Bionic Beaver`.
+    pub codename: String,
+    /// `series`: the lower-case codename of the release, such as `bionic`.
+    pub series: String,
+    /// `created`: when development of the release started.
+    pub created: Option<Date>,
+    /// `release`: when the release came out, if it has.
+    pub release: Option<Date>,
+    /// `eol`: the end of standard support.
+    pub eol: Option<Date>,
+    /// `eol-server`: the end of support for server packages, as used by
+    /// Ubuntu LTS releases before ESM.
+    pub eol_server: Option<Date>,
+    /// `eol-esm`: the end of Ubuntu Expanded Security Maintenance.
+    pub eol_esm: Option<Date>,
+    /// `eol-lts`: the end of Debian Long Term Support.
+    pub eol_lts: Option<Date>,
+    /// `eol-elts`: the end of Debian Extended LTS.
+    pub eol_elts: Option<Date>,
+    /// All other columns and their values, such as `eol-legacy`.
+    pub extra: BTreeMap<String, String>,
+}
+
+impl DistroInfoRecord {
+    /// Returns the lifecycle of the release, if it has come out.
+    ///
+    /// The extended end is the first listed of ESM, ELTS, LTS or server
+    /// support, matching the tables compiled into this crate.
+    pub fn lifecycle(&self) -> Option<Lifecycle> {
+        let extended_end = self.eol_esm
+            .or(self.eol_elts)
+            .or(self.eol_lts)
+            .or(self.eol_server);
+        Some(Lifecycle {
+            released: self.release?,
+            support_end: self.eol,
+            extended_end,
+        })
+    }
+
+    /// Returns the release as one unknown to this crate.
+    pub fn unknown_release(&self) -> UnknownRelease {
+        UnknownRelease {
+            version: self.version,
+            codename: Some(self.series.clone()),
+            name: Some(self.codename.clone()),
+            lifecycle: self.lifecycle(),
+            support_end: None,
+        }
+    }
+
+    /// Returns whether the version number of the record matches `version`.
+    ///
+    /// Records for Debian 7 and later only list the major version number.
+    fn matches(&self, version: Version, exact: bool) -> bool {
+        match self.version {
+            Some(own) if own.major == version.major => {
+                own.minor == version.minor || (!exact && own.minor == 0)
+            },
+            _ => false,
+        }
+    }
+}
+
+/// The contents of a `distro-info-data` table, from oldest to newest release.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DistroInfo {
+    /// The releases listed in the table.
+    pub records: Vec<DistroInfoRecord>,
+}
+
+impl DistroInfo {
+    /// Reads the host's table for the distribution `name`, such as `ubuntu`,
+    /// from `/usr/share/distro-info/<name>.csv`.
+    pub fn read(name: &str) -> io::Result<DistroInfo> {
+        Self::read_in(Path::new("/"), name)
+    }
+
+    /// Reads and parses the table at `path`.
+    pub fn read_from<P: AsRef<Path>>(path: P) -> io::Result<DistroInfo> {
+        fs::read_to_string(path).map(|s| Self::parse(&s))
+    }
+
+    pub(crate) fn read_in(root: &Path, name: &str) -> io::Result<DistroInfo> {
+        Self::read_from(root.join(&DIR[1..]).join(format!("{}.csv", name)))
+    }
+
+    /// Parses the contents of a table whose first line names the columns.
+    ///
+    /// Rows without a series are ignored, as are values that fail to parse.
+    pub fn parse(contents: &str) -> DistroInfo {
+        let mut lines = contents.lines().filter(|line| !line.trim().is_empty());
+        let columns: Vec<&str> = match lines.next() {
+            Some(header) => header.split(',').map(str::trim).collect(),
+            None => return DistroInfo::default(),
+        };
+
+        let records = lines.filter_map(|line| {
+            let mut record = DistroInfoRecord::default();
+            for (&column, value) in columns.iter().zip(line.split(',')) {
+                let value = value.trim();
+                let date = || value.parse().ok();
+                match column {
+                    "version" => {
+                        let mut words = value.split_whitespace();
+                        record.version = words.next().and_then(|v| v.parse().ok());
+                        record.is_lts = words.any(|word| word == "LTS");
+                    },
+                    "codename"   => record.codename = value.to_owned(),
+                    "series"     => record.series = value.to_owned(),
+                    "created"    => record.created = date(),
+                    "release"    => record.release = date(),
+                    "eol"        => record.eol = date(),
+                    "eol-server" => record.eol_server = date(),
+                    "eol-esm"    => record.eol_esm = date(),
+                    "eol-lts"    => record.eol_lts = date(),
+                    "eol-elts"   => record.eol_elts = date(),
+                    _ if value.is_empty() => {},
+                    _ => {
+                        record.extra.insert(column.to_owned(), value.to_owned());
+                    },
+                }
+            }
+            if record.series.is_empty() {
+                None
+            } else {
+                Some(record)
+            }
+        }).collect();
+
+        DistroInfo { records }
+    }
+
+    /// Returns the record for the series, such as `bionic`.
+    pub fn find_series(&self, series: &str) -> Option<&DistroInfoRecord> {
+        self.records.iter().find(|record| record.series == series)
+    }
+
+    /// Returns the record for the version, such as `18.04` or `12.5`.
+    pub fn find_version(&self, version: Version) -> Option<&DistroInfoRecord> {
+        let records = || self.records.iter();
+        records()
+            .find(|record| record.matches(version, true))
+            .or_else(|| records().find(|record| record.matches(version, false)))
+    }
+
+    /// Returns the record for a series, display name or version, ignoring
+    /// case.
+    pub fn find(&self, s: &str) -> Option<&DistroInfoRecord> {
+        let s = s.trim().to_lowercase();
+        self.records.iter()
+            .find(|record| record.series == s || record.codename.to_lowercase() == s)
+            .or_else(|| s.parse().ok().and_then(|version| self.find_version(version)))
+    }
+
+    /// Parses a release like [`KnownRelease::parse`], falling back to this
+    /// table for releases not known to this crate.
+    ///
+    /// [`KnownRelease::parse`]: ../../../release/trait.KnownRelease.html#method.parse
+    pub fn release<R: KnownRelease>(&self, s: &str) -> Option<Release<R>> {
+        R::parse(s).ok().map(Release::Known).or_else(|| {
+            let record = self.find(s)?;
+            let known = record.version
+                .and_then(R::from_version)
+                .or_else(|| R::from_codename(&record.series));
+            Some(match known {
+                Some(known) => Release::Known(known),
+                None => Release::Unknown(record.unknown_release()),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use info::os::linux::{debian, ubuntu};
+    use info::os::linux::fixtures::fixture;
+
+    fn read(fixture_name: &str, name: &str) -> DistroInfo {
+        DistroInfo::read_in(&fixture(fixture_name), name).unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let info = DistroInfo::parse("\
+            version,codename,series,created,release,eol,eol-server,eol-esm,eol-legacy\n\
+            18.04 LTS,Bionic Beaver,bionic,2017-10-19,2018-04-26,2023-05-31,2023-05-31,2028-04-26,2030-04-30\n\
+            18.10,Cosmic Cuttlefish,cosmic,2018-04-26,2018-10-18,2019-07-18\n\
+            ,,\n\
+        ");
+        assert_eq!(info.records.len(), 2);
+
+        let bionic = &info.records[0];
+        assert_eq!(bionic.version, Some(Version::new(18, 4, 0)));
+        assert!(bionic.is_lts);
+        assert_eq!(bionic.codename, "Bionic Beaver");
+        assert_eq!(bionic.series, "bionic");
+        assert_eq!(bionic.eol_esm, Some(Date::new(2028, 4, 26)));
+        assert_eq!(bionic.extra["eol-legacy"], "2030-04-30");
+
+        let cosmic = &info.records[1];
+        assert!(!cosmic.is_lts);
+        assert_eq!(cosmic.eol_server, None);
+        assert!(cosmic.extra.is_empty());
+    }
+
+    #[test]
+    fn find() {
+        let debian = read("debian-12", "debian");
+        let series = |version| debian.find_version(version).map(|r| &r.series[..]);
+        assert_eq!(series(Version::new(2, 0, 0)), Some("hamm"));
+        assert_eq!(series(Version::new(2, 1, 0)), Some("slink"));
+        assert_eq!(series(Version::new(6, 0, 10)), Some("squeeze"));
+        assert_eq!(series(Version::new(12, 5, 0)), Some("bookworm"));
+        assert_eq!(series(Version::new(99, 0, 0)), None);
+
+        assert_eq!(debian.find("Bookworm").map(|r| r.version), Some(Some(12.into())));
+        assert_eq!(debian.find("sid").map(|r| r.version), Some(None));
+
+        let ubuntu = read("ubuntu-24.04", "ubuntu");
+        assert_eq!(ubuntu.find("24.04").map(|r| &r.codename[..]), Some("Noble Numbat"));
+        assert_eq!(ubuntu.find("noble numbat").map(|r| &r.series[..]), Some("noble"));
+    }
+
+    #[test]
+    fn release() {
+        let info = read("ubuntu-24.04", "ubuntu");
+        assert_eq!(
+            info.release("bionic"),
+            Some(Release::Known(ubuntu::OsRelease::BionicBeaver)),
+        );
+
+        // Releases before Feisty Fawn are only known to `distro-info`
+        let warty = info.release::<ubuntu::OsRelease>("warty").unwrap();
+        assert!(!warty.is_known());
+        assert_eq!(warty.name(), Some("Warty Warthog"));
+        assert_eq!(warty.codename(), Some("warty"));
+        assert_eq!(warty.version(), Some(Version::new(4, 10, 0)));
+        assert_eq!(warty.lifecycle(), Some(Lifecycle {
+            released: Date::new(2004, 10, 20),
+            support_end: Some(Date::new(2006, 4, 30)),
+            extended_end: None,
+        }));
+
+        let resolute = info.release("resolute");
+        assert_eq!(resolute, Some(Release::Known(ubuntu::OsRelease::ResoluteRaccoon)));
+    }
+
+    /// The compiled tables should agree with `distro-info-data`.
+    // The tables are upstream's, as recorded in `tests/fixtures/README.md`
+    #[test]
+    fn compiled_tables() {
+        fn check<R: KnownRelease>(info: &DistroInfo) {
+            for release in R::iter() {
+                let record = info.find_series(release.codename().unwrap()).unwrap();
+                assert_eq!(record.codename, release.name());
+                assert_eq!(record.lifecycle(), Some(release.lifecycle()), "{:?}", release);
+            }
+        }
+        check::<debian::OsRelease>(&read("debian-12", "debian"));
+        check::<ubuntu::OsRelease>(&read("ubuntu-24.04", "ubuntu"));
+    }
+}
@@ -0,0 +1,193 @@
+//! Utilities specific to Fedora Linux.
+
+use super::rhel;
+use version::Version;
+
+releases! {
+    /// The release name of a known Fedora version.
+    ///
+    /// Fedora releases are referred to by number and are supported until four
+    /// weeks after the release that follows the next one, with no extended
+    /// support. Releases are known as of Fedora 28, from which RHEL 8 was
+    /// branched.
+    pub enum OsRelease: "Fedora" {
+        /// Fedora 28.
+        Fedora28 {
+            version: (28, _),
+            name: "28",
+            codename: -,
+            lifecycle: [(2018, 05, 01), (2019, 05, 28), -],
+            glibc: (2, 27, 0),
+            rhel: Some(rhel::OsRelease::Rhel8),
+        }
+
+        /// Fedora 29.
+        Fedora29 {
+            version: (29, _),
+            name: "29",
+            codename: -,
+            lifecycle: [(2018, 10, 30), (2019, 11, 26), -],
+            glibc: (2, 28, 0),
+            rhel: None,
+        }
+
+        /// Fedora 30.
+        Fedora30 {
+            version: (30, _),
+            name: "30",
+            codename: -,
+            lifecycle: [(2019, 04, 30), (2020, 05, 26), -],
+            glibc: (2, 29, 0),
+            rhel: None,
+        }
+
+        /// Fedora 31.
+        Fedora31 {
+            version: (31, _),
+            name: "31",
+            codename: -,
+            lifecycle: [(2019, 10, 29), (2020, 11, 24), -],
+            glibc: (2, 30, 0),
+            rhel: None,
+        }
+
+        /// Fedora 32.
+        Fedora32 {
+            version: (32, _),
+            name: "32",
+            codename: -,
+            lifecycle: [(2020, 04, 28), (2021, 05, 25), -],
+            glibc: (2, 31, 0),
+            rhel: None,
+        }
+
+        /// Fedora 33.
+        Fedora33 {
+            version: (33, _),
+            name: "33",
+            codename: -,
+            lifecycle: [(2020, 10, 27), (2021, 11, 30), -],
+            glibc: (2, 32, 0),
+            rhel: None,
+        }
+
+        /// Fedora 34.
+        Fedora34 {
+            version: (34, _),
+            name: "34",
+            codename: -,
+            lifecycle: [(2021, 04, 27), (2022, 06, 07), -],
+            glibc: (2, 33, 0),
+            rhel: Some(rhel::OsRelease::Rhel9),
+        }
+
+        /// Fedora 35.
+        Fedora35 {
+            version: (35, _),
+            name: "35",
+            codename: -,
+            lifecycle: [(2021, 11, 02), (2022, 12, 13), -],
+            glibc: (2, 34, 0),
+            rhel: None,
+        }
+
+        /// Fedora 36.
+        Fedora36 {
+            version: (36, _),
+            name: "36",
+            codename: -,
+            lifecycle: [(2022, 05, 10), (2023, 05, 16), -],
+            glibc: (2, 35, 0),
+            rhel: None,
+        }
+
+        /// Fedora 37.
+        Fedora37 {
+            version: (37, _),
+            name: "37",
+            codename: -,
+            lifecycle: [(2022, 11, 15), (2023, 12, 05), -],
+            glibc: (2, 36, 0),
+            rhel: None,
+        }
+
+        /// Fedora 38.
+        Fedora38 {
+            version: (38, _),
+            name: "38",
+            codename: -,
+            lifecycle: [(2023, 04, 18), (2024, 05, 21), -],
+            glibc: (2, 37, 0),
+            rhel: None,
+        }
+
+        /// Fedora 39.
+        Fedora39 {
+            version: (39, _),
+            name: "39",
+            codename: -,
+            lifecycle: [(2023, 11, 07), (2024, 11, 26), -],
+            glibc: (2, 38, 0),
+            rhel: None,
+        }
+
+        /// Fedora 40.
+        Fedora40 {
+            version: (40, _),
+            name: "40",
+            codename: -,
+            lifecycle: [(2024, 04, 23), (2025, 05, 13), -],
+            glibc: (2, 39, 0),
+            rhel: Some(rhel::OsRelease::Rhel10),
+        }
+
+        /// Fedora 41.
+        Fedora41 {
+            version: (41, _),
+            name: "41",
+            codename: -,
+            lifecycle: [(2024, 10, 29), (2025, 12, 15), -],
+            glibc: (2, 40, 0),
+            rhel: None,
+        }
+
+        /// Fedora 42.
+        Fedora42 {
+            version: (42, _),
+            name: "42",
+            codename: -,
+            lifecycle: [(2025, 04, 15), (2026, 05, 12), -],
+            glibc: (2, 41, 0),
+            rhel: None,
+        }
+
+        /// Fedora 43.
+        Fedora43 {
+            version: (43, _),
+            name: "43",
+            codename: -,
+            lifecycle: [(2025, 10, 28), -, -],
+            glibc: (2, 42, 0),
+            rhel: None,
+        }
+    }
+
+    struct Data {
+        glibc: (u64, u64, u64),
+        rhel: Option<rhel::OsRelease>,
+    }
+}
+
+impl OsRelease {
+    /// Returns the version of the GNU C Library that the release ships.
+    #[inline]
+    pub fn glibc_version(self) -> Version {
+        self.data().glibc.into()
+    }
+
+    /// Returns the RHEL release that was branched from this release, if any.
+    #[inline]
+    pub fn rhel(self) -> Option<rhel::OsRelease> {
+        self.data().rhel
+    }
+}
@@ -0,0 +1,11 @@
+//! Fake roots of Linux systems, under `tests/fixtures`.
+
+use std::path::{Path, PathBuf};
+
+/// Returns the root of the fixture called `name`.
+pub(crate) fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name)
+}
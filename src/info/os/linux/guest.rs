@@ -0,0 +1,86 @@
+//! Linux systems that run within another operating system, rather than
+//! directly on the hardware or in a general-purpose virtual machine.
+
+use info::Release;
+use info::os::windows;
+
+/// The operating system that a Linux system is a guest of.
+///
+/// The guest's own distribution is still reported in
+/// [`OsInfo::meta`](../../../struct.OsInfo.html#structfield.meta).
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Guest {
+    /// A Crostini container on ChromeOS, which runs Debian.
+    Crostini {
+        /// The ChromeOS milestone of the host, such as 120, from
+        /// `/dev/.cros_milestone`.
+        milestone: Option<u64>,
+    },
+    /// A distribution running in the Windows Subsystem for Linux (WSL).
+    Wsl {
+        /// The version of WSL, if found.
+        version: Option<WslVersion>,
+        /// The name of the distribution as registered with WSL, such as
+        /// `Ubuntu-22.04`, from the `WSL_DISTRO_NAME` environment variable.
+        distro_name: Option<String>,
+        /// The build number of the Windows host, such as 19041, which only
+        /// the WSL 1 kernel release reports.
+        windows_build: Option<u64>,
+        /// The Windows release of the host, if found.
+        windows: Option<Release<windows::OsRelease>>,
+    },
+}
+
+/// A version of the Windows Subsystem for Linux.
+///
+/// The two differ in how Linux is run and so in how the Windows file system
+/// under `/mnt/c` behaves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum WslVersion {
+    /// WSL 1, which translates Linux system calls for the Windows kernel and
+    /// accesses Windows files directly.
+    Wsl1,
+    /// WSL 2, which runs a Linux kernel in a lightweight virtual machine and
+    /// accesses Windows files over the slower 9P protocol.
+    Wsl2,
+}
+
+impl WslVersion {
+    /// Returns the version of WSL whose kernel has the release `release`, or
+    /// the `/proc/version` banner `release`, or `None` if it is not a WSL
+    /// kernel.
+    ///
+    /// WSL 1 reports releases like `4.4.0-19041-Microsoft`, and WSL 2 like
+    /// `5.15.133.1-microsoft-standard-WSL2`.
+    pub fn from_kernel(release: &str) -> Option<WslVersion> {
+        if release.contains("WSL2") || release.contains("microsoft-standard") {
+            Some(WslVersion::Wsl2)
+        } else if release.contains("Microsoft") {
+            Some(WslVersion::Wsl1)
+        } else if release.contains("microsoft") {
+            Some(WslVersion::Wsl2)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wsl_kernels() {
+        let pairs = [
+            ("4.4.0-19041-Microsoft",              Some(WslVersion::Wsl1)),
+            ("5.15.133.1-microsoft-standard-WSL2", Some(WslVersion::Wsl2)),
+            ("4.19.104-microsoft-standard",        Some(WslVersion::Wsl2)),
+            ("6.1.0-18-amd64",                     None),
+        ];
+        for &(release, version) in &pairs {
+            assert_eq!(WslVersion::from_kernel(release), version, "{}", release);
+        }
+    }
+}
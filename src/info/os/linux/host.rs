@@ -0,0 +1,1266 @@
+//! Querying information about the host Linux system.
+
+use std::env;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use version::{KernelVersion, Version, OsVersion};
+use info::{KnownRelease, OsInfo, OsMeta, Release, UnknownRelease};
+use info::os::android::BuildProp;
+use info::os::windows;
+use super::{chromeos, debian, immutable, nixos, os_release, rhel, rolling, suse, ubuntu};
+use super::{Base, Derivative, DistroInfo, Guest, Immutable, OsReleaseFile, WslVersion};
+use super::derivative::devuan_base;
+
+/// Files that contain the distribution version as their first version-like
+/// word, in order of preference.
+const RELEASE_FILES: &[&str] = &[
+    "etc/debian_version",
+    "etc/redhat-release",
+    "etc/fedora-release",
+    "etc/centos-release",
+    "etc/system-release",
+    "etc/alpine-release",
+    "etc/arch-release",
+    "etc/gentoo-release",
+    "etc/slackware-version",
+];
+
+/// Files that name a Red Hat family distribution, such as
+/// `Rocky Linux release 9.3 (Blue Onyx)`, in order of preference.
+const REDHAT_RELEASE_FILES: &[&str] = &[
+    "etc/oracle-release",
+    "etc/redhat-release",
+    "etc/system-release",
+];
+
+/// The names that start the Red Hat family release files and the `os-release`
+/// `ID` of each distribution.
+const REDHAT_NAMES: &[(&str, &str)] = &[
+    ("Red Hat Enterprise Linux", "rhel"),
+    ("CentOS",                   "centos"),
+    ("Rocky Linux",              "rocky"),
+    ("AlmaLinux",                "almalinux"),
+    ("Oracle Linux",             "ol"),
+    ("Fedora",                   "fedora"),
+    ("Amazon Linux",             "amzn"),
+];
+
+/// Files whose presence identifies a distribution without an `os-release`
+/// file, and the `os-release` `ID` of each.
+const MARKER_FILES: &[(&str, &str)] = &[
+    ("etc/alpine-release", "alpine"),
+    ("etc/arch-release",   "arch"),
+    ("etc/gentoo-release", "gentoo"),
+    ("system/build.prop",  "android"),
+];
+
+/// Where Termux is installed on Android for the primary user, if its `PREFIX`
+/// is not set.
+const TERMUX_PREFIX: &str = "data/data/com.termux/files/usr";
+
+pub(crate) fn get_info() -> OsInfo {
+    let mut info = Host::new(Path::new("/")).info();
+    if info.kernel.is_none() {
+        // `/proc` may not be mounted, such as in a chroot
+        info.kernel = KernelVersion::get();
+    }
+    // The variable is inherited by other environments, so it only names the
+    // distribution once WSL itself has been detected
+    if let Some(Guest::Wsl { distro_name, .. }) = &mut info.guest {
+        *distro_name = env::var("WSL_DISTRO_NAME").ok();
+    }
+    // Termux sets its prefix, which differs for secondary users and forks
+    if let OsMeta::Android { termux_prefix, .. } = &mut info.meta {
+        if let Some(prefix) = env::var_os("PREFIX") {
+            *termux_prefix = Some(PathBuf::from(prefix));
+        }
+    }
+    info
+}
+
+pub(crate) fn get_version() -> Option<OsVersion> {
+    Host::new(Path::new("/")).version()
+}
+
+/// The sources of information about a Linux system whose files are under
+/// `root`.
+struct Host<'a> {
+    root: &'a Path,
+    os_release: Option<OsReleaseFile>,
+    lsb_release: Vec<(String, String)>,
+    id: Option<String>,
+}
+
+impl<'a> Host<'a> {
+    fn new(root: &'a Path) -> Host<'a> {
+        let os_release = OsReleaseFile::read_in(root).ok();
+        let lsb_release = fs::read_to_string(root.join("etc/lsb-release"))
+            .map(|s| os_release::assignments(&s))
+            .unwrap_or_default();
+        let mut host = Host { root, os_release, lsb_release, id: None };
+        host.id = host.detect_id();
+        host
+    }
+
+    /// Reads the file at `path` relative to `root` into a string.
+    fn read(&self, path: &str) -> Option<String> {
+        fs::read_to_string(self.root.join(path)).ok()
+    }
+
+    /// Returns the value for `key` in the `os-release` file.
+    fn os_release(&self, key: &str) -> Option<&str> {
+        self.os_release.as_ref().and_then(|file| file.get(key))
+    }
+
+    /// Returns the value for `key` in `/etc/lsb-release`.
+    fn lsb_release(&self, key: &str) -> Option<&str> {
+        self.lsb_release.iter()
+            .rev()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns whether the `os-release` `ID` or `ID_LIKE` includes `id`.
+    fn is_like(&self, id: &str) -> bool {
+        self.os_release.as_ref().map(|file| file.is_like(id)) == Some(true)
+    }
+
+    /// Returns the lower-case identifier of the distribution.
+    fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Identifies the distribution from the `os-release` `ID`, its
+    /// `/etc/lsb-release` or the files that only it installs.
+    fn detect_id(&self) -> Option<String> {
+        self.os_release("ID")
+            .or_else(|| self.lsb_release("DISTRIB_ID"))
+            .map(str::to_lowercase)
+            .or_else(|| {
+                self.lsb_release("CHROMEOS_RELEASE_NAME").map(|_| "chromeos".to_owned())
+            })
+            .or_else(|| self.redhat_id().map(ToOwned::to_owned))
+            .or_else(|| {
+                MARKER_FILES.iter()
+                    .find(|(path, _)| self.root.join(path).exists())
+                    .map(|&(_, id)| id.to_owned())
+            })
+    }
+
+    /// Identifies a Red Hat family distribution without an `os-release` file
+    /// from `/etc/system-release-cpe` or its release file.
+    fn redhat_id(&self) -> Option<&'static str> {
+        let release_file = || {
+            REDHAT_RELEASE_FILES.iter()
+                .filter_map(|path| self.read(path))
+                .filter_map(|s| {
+                    REDHAT_NAMES.iter()
+                        .find(|(name, _)| s.starts_with(name))
+                        .map(|&(_, id)| id)
+                })
+                .next()
+        };
+        self.cpe().and_then(|cpe| cpe.os_release_id()).or_else(release_file)
+    }
+
+    /// Parses `/etc/system-release-cpe`.
+    fn cpe(&self) -> Option<rhel::Cpe> {
+        rhel::Cpe::parse(&self.read("etc/system-release-cpe")?)
+    }
+
+    /// Returns the codenames that the distribution reports for its release,
+    /// in order of preference.
+    fn codenames(&self) -> Vec<&str> {
+        let os_release = ["VERSION_CODENAME", "UBUNTU_CODENAME"];
+        os_release.iter()
+            .filter_map(|key| self.os_release(key))
+            .chain(self.lsb_release("DISTRIB_CODENAME"))
+            .collect()
+    }
+
+    fn info(&self) -> OsInfo {
+        OsInfo {
+            meta: self.meta(),
+            version: self.version(),
+            kernel: self.kernel(),
+            immutable: self.immutable(),
+            guest: self.guest(),
+        }
+    }
+
+    fn meta(&self) -> OsMeta {
+        if let Some(meta) = self.derivative_meta() {
+            return meta;
+        }
+        let id = self.id();
+        match id {
+            Some("ubuntu") => OsMeta::Ubuntu {
+                release: self.ubuntu_release(),
+                point_release: self.point_release(),
+            },
+            Some("ubuntu-core") => OsMeta::Ubuntu {
+                release: self.ubuntu_core_release(),
+                point_release: None,
+            },
+            Some("debian") => self.debian_meta(),
+            None if self.read("etc/debian_version").is_some() => self.debian_meta(),
+            Some("fedora") => self.fedora_meta(),
+            Some("amzn") => OsMeta::AmazonLinux {
+                release: find_release(self.distro_version(), &[]),
+            },
+            Some("alpine") => self.alpine_meta(),
+            Some("nixos") => self.nixos_meta(),
+            Some("android") => self.android_meta(),
+            Some("chromeos") => self.chromeos_meta(),
+            _ => if let Some(distro) = id.and_then(rhel::Distro::from_id) {
+                self.rhel_meta(distro)
+            } else if let Some(distro) = id.and_then(suse::Distro::from_id) {
+                self.suse_meta(distro)
+            } else if let Some(distro) = id.and_then(rolling::Distro::from_id) {
+                self.rolling_meta(distro)
+            } else {
+                OsMeta::UnknownLinux {
+                    name: self.os_release("NAME").map(ToOwned::to_owned),
+                    id: id.map(ToOwned::to_owned),
+                }
+            },
+        }
+    }
+
+    fn fedora_meta(&self) -> OsMeta {
+        // Releases newer than the table still report when their support ends
+        let support_end = self.os_release.as_ref()
+            .and_then(OsReleaseFile::support_end_date);
+        let release = find_release(self.distro_version(), &[]).map(|release| {
+            match release {
+                Release::Unknown(release) => {
+                    Release::Unknown(UnknownRelease { support_end, ..release })
+                },
+                release => release,
+            }
+        });
+        OsMeta::Fedora { release }
+    }
+
+    fn chromeos_meta(&self) -> OsMeta {
+        OsMeta::ChromeOs {
+            milestone: self.lsb_release("CHROMEOS_RELEASE_CHROME_MILESTONE")
+                .or_else(|| self.os_release("VERSION_ID"))
+                .and_then(|milestone| milestone.parse().ok()),
+            platform_version: self.lsb_release("CHROMEOS_RELEASE_VERSION")
+                .or_else(|| self.os_release("BUILD_ID"))
+                .and_then(find_version),
+            channel: self.lsb_release("CHROMEOS_RELEASE_TRACK")
+                .and_then(chromeos::Channel::from_track),
+            board: self.lsb_release("CHROMEOS_RELEASE_BOARD")
+                .and_then(|board| board.split("-signed-").next())
+                .map(ToOwned::to_owned),
+        }
+    }
+
+    fn android_meta(&self) -> OsMeta {
+        let build_prop = self.build_prop();
+        let release = build_prop.os_release().map(Release::Known).or_else(|| {
+            Release::from_parts(None, build_prop.release, None)
+        });
+        let termux_prefix = if self.root.join(TERMUX_PREFIX).is_dir() {
+            Some(Path::new("/").join(TERMUX_PREFIX))
+        } else {
+            None
+        };
+
+        OsMeta::Android {
+            release,
+            api_level: build_prop.api_level,
+            security_patch: build_prop.security_patch,
+            termux_prefix,
+        }
+    }
+
+    fn build_prop(&self) -> BuildProp {
+        BuildProp::parse(&self.read("system/build.prop").unwrap_or_default())
+    }
+
+    fn nixos_meta(&self) -> OsMeta {
+        let version = ["BUILD_ID", "VERSION"].iter()
+            .filter_map(|key| self.os_release(key))
+            .filter_map(|version| nixos::NixVersion::parse(version).ok())
+            .next();
+        let release = version.as_ref().map(|version| version.release)
+            .or_else(|| self.distro_version());
+        let generation = fs::read_link(self.root.join("nix/var/nix/profiles/system"))
+            .ok()
+            .and_then(|target| nixos::generation(target.to_str()?));
+
+        OsMeta::NixOs {
+            release: find_release(release, &self.codenames()),
+            version,
+            generation,
+            channel: self.read("root/.nix-channels").and_then(|s| nixos::channel(&s)),
+        }
+    }
+
+    fn rolling_meta(&self, distro: rolling::Distro) -> OsMeta {
+        let date = ["BUILD_ID", "IMAGE_VERSION"].iter()
+            .filter_map(|key| self.os_release(key))
+            .filter_map(rolling::snapshot_date)
+            .next();
+        let profile = match distro {
+            rolling::Distro::Gentoo => self.gentoo_profile(),
+            _ => None,
+        };
+        let branch = match distro {
+            rolling::Distro::Manjaro => self.manjaro_branch(),
+            _ => None,
+        };
+
+        OsMeta::Rolling {
+            distro,
+            snapshot: rolling::Snapshot {
+                date,
+                build_id: self.os_release("BUILD_ID").map(ToOwned::to_owned),
+                profile,
+                branch,
+            },
+        }
+    }
+
+    /// Returns the Gentoo profile that `/etc/portage/make.profile` links to,
+    /// relative to the `profiles` directory of the repository.
+    fn gentoo_profile(&self) -> Option<String> {
+        let target = fs::read_link(self.root.join("etc/portage/make.profile")).ok()?;
+        let target = target.to_str()?;
+        let start = target.rfind("profiles/").map_or(0, |i| i + "profiles/".len());
+        Some(target[start..].trim_end_matches('/').to_owned())
+    }
+
+    /// Returns the Manjaro branch set in `/etc/pacman-mirrors.conf`, which is
+    /// the stable branch unless set otherwise.
+    fn manjaro_branch(&self) -> Option<rolling::Branch> {
+        let config = self.read("etc/pacman-mirrors.conf")?;
+        let branch = config.lines()
+            .rev()
+            .find_map(|line| {
+                let mut parts = line.splitn(2, '=');
+                match (parts.next()?.trim(), parts.next()) {
+                    ("Branch", Some(value)) => Some(value),
+                    _ => None,
+                }
+            })
+            .and_then(rolling::Branch::from_name);
+        Some(branch.unwrap_or(rolling::Branch::Stable))
+    }
+
+    fn alpine_meta(&self) -> OsMeta {
+        let point_release = self.point_release();
+        let version = point_release.or_else(|| self.distro_version());
+        OsMeta::Alpine {
+            release: find_release(version, &[]),
+            point_release,
+            edge: self.is_alpine_edge(),
+        }
+    }
+
+    /// Returns whether the host follows the Alpine Linux edge branch, whose
+    /// release reads like `3.21_alpha20240807` and whose untagged APK
+    /// repositories are under `edge/`.
+    fn is_alpine_edge(&self) -> bool {
+        let release = self.read("etc/alpine-release")
+            .or_else(|| self.os_release("VERSION_ID").map(ToOwned::to_owned))
+            .unwrap_or_default();
+        if release.contains("_alpha") {
+            return true;
+        }
+        let repositories = self.read("etc/apk/repositories").unwrap_or_default();
+        repositories.lines().map(str::trim).any(|line| {
+            !line.starts_with('#') && !line.starts_with('@') && line.contains("/edge/")
+        })
+    }
+
+    fn suse_meta(&self, distro: suse::Distro) -> OsMeta {
+        let version_id = self.os_release("VERSION_ID");
+        let version = match distro {
+            suse::Distro::Sles | suse::Distro::Sled => {
+                // `VERSION` names the service pack, as in `15-SP5`
+                self.os_release("VERSION")
+                    .or(version_id)
+                    .and_then(|s| s.parse().ok())
+                    .map(|version| match version {
+                        suse::SuseVersion::Release(version) => {
+                            suse::SuseVersion::ServicePack {
+                                major: version.major,
+                                service_pack: version.minor,
+                            }
+                        },
+                        version => version,
+                    })
+            },
+            _ => version_id.and_then(|s| s.parse().ok()),
+        };
+
+        // Tumbleweed shared the `ID` of `opensuse` with Leap 42.x
+        let is_tumbleweed = match version {
+            Some(suse::SuseVersion::Snapshot(_)) => true,
+            _ => self.os_release("NAME") == Some("openSUSE Tumbleweed"),
+        };
+        let distro = match distro {
+            suse::Distro::Leap if is_tumbleweed => suse::Distro::Tumbleweed,
+            distro => distro,
+        };
+
+        // Leap is numbered apart from the SLE release it is built from
+        let number = version.and_then(|version| version.version());
+        let release = match distro {
+            suse::Distro::Tumbleweed => None,
+            suse::Distro::Leap => {
+                number.and_then(suse::OsRelease::from_leap_version).map(Release::Known)
+            },
+            _ => Release::from_parts(number.and_then(suse::OsRelease::new), number, None),
+        };
+
+        OsMeta::Suse { distro, version, release }
+    }
+
+    fn rhel_meta(&self, distro: rhel::Distro) -> OsMeta {
+        let distro = match distro {
+            rhel::Distro::CentOs if self.is_centos_stream() => {
+                rhel::Distro::CentOsStream
+            },
+            distro => distro,
+        };
+        let point_release = self.point_release();
+        let version = point_release.or_else(|| self.distro_version());
+        OsMeta::Rhel {
+            distro,
+            release: find_release(version, &[]),
+            point_release,
+        }
+    }
+
+    /// Returns whether the host runs CentOS Stream rather than CentOS Linux,
+    /// which share the `ID` of `centos`.
+    fn is_centos_stream(&self) -> bool {
+        let name = self.os_release("NAME")
+            .map(ToOwned::to_owned)
+            .or_else(|| self.read("etc/redhat-release"));
+        self.id() == Some("centos") &&
+            name.map(|name| name.contains("Stream")) == Some(true)
+    }
+
+    fn ubuntu_release(&self) -> Option<Release<ubuntu::OsRelease>> {
+        self.release(self.distro_version(), &self.codenames(), "ubuntu")
+    }
+
+    /// Ubuntu Core is built from the LTS release of the same year, such as
+    /// Ubuntu Core 22 from Ubuntu 22.04.
+    fn ubuntu_core_release(&self) -> Option<Release<ubuntu::OsRelease>> {
+        let version = self.distro_version()
+            .map(|version| Version::new(version.major, 4, 0));
+        self.release(version, &[], "ubuntu")
+    }
+
+    fn debian_meta(&self) -> OsMeta {
+        let debian_version = self.read("etc/debian_version").unwrap_or_default();
+        let point_release = self.point_release();
+
+        // Testing and unstable name the release in development, as in
+        // `trixie/sid`, and can only be told apart by the APT sources
+        let development = debian_version.trim().split('/').next()
+            .filter(|_| debian_version.trim().ends_with("/sid"));
+        let is_unstable = || {
+            self.apt_suites().iter().any(|suite| suite == "sid" || suite == "unstable")
+        };
+        let suite = match (point_release, development) {
+            (Some(_), _) => Some(debian::Suite::Stable),
+            (None, Some(_)) if is_unstable() => Some(debian::Suite::Unstable),
+            (None, Some(_)) => Some(debian::Suite::Testing),
+            (None, None) => None,
+        };
+
+        let mut codenames = self.codenames();
+        codenames.extend(development);
+        let version = point_release.or_else(|| self.distro_version());
+
+        OsMeta::Debian {
+            release: self.release(version, &codenames, "debian"),
+            point_release,
+            suite,
+        }
+    }
+
+    /// Returns the lower-case identifier of a distribution derived from Debian
+    /// or Ubuntu, if the host runs one.
+    fn derivative_id(&self) -> Option<String> {
+        let lsb_id = self.lsb_release("DISTRIB_ID").map(str::to_lowercase);
+        let id = self.id()?;
+        let derivative = match id {
+            // Derivatives that keep the `ID` of Debian
+            "debian" if lsb_id.as_deref() == Some("mx") => Derivative::Mx.id(),
+            "debian" if self.root.join("etc/rpi-issue").exists() => {
+                Derivative::Raspbian.id()
+            },
+            "debian" | "ubuntu" | "ubuntu-core" => return None,
+            other if Derivative::from_id(other).is_some() => other,
+            other if self.is_like("ubuntu") || self.is_like("debian") => other,
+            _ => return None,
+        };
+        Some(derivative.to_owned())
+    }
+
+    /// Returns the value of a derivative's `os-release` or `/etc/lsb-release`
+    /// key, preferring the file that describes the derivative itself.
+    fn derivative_field(&self, os_release_key: &str, lsb_key: &str) -> Option<&str> {
+        let os_release = self.os_release(os_release_key);
+        let lsb_release = self.lsb_release(lsb_key);
+        if self.id() == Some("debian") {
+            lsb_release.or(os_release)
+        } else {
+            os_release.or(lsb_release)
+        }
+    }
+
+    fn derivative_meta(&self) -> Option<OsMeta> {
+        let id = self.derivative_id()?;
+        let derivative = Derivative::from_id(&id);
+        let codename = self.derivative_field("VERSION_CODENAME", "DISTRIB_CODENAME");
+        let name = match derivative {
+            Some(derivative) if self.id() == Some("debian") => {
+                Some(<&str>::from(derivative))
+            },
+            _ => self.os_release("NAME"),
+        };
+
+        Some(OsMeta::Derivative {
+            derivative,
+            name: name.map(ToOwned::to_owned),
+            version: self.derivative_version(),
+            codename: codename.map(ToOwned::to_owned),
+            base: self.derivative_base(derivative, codename),
+            id,
+        })
+    }
+
+    /// Queries a derivative's own version, such as `21.3` for Linux Mint.
+    fn derivative_version(&self) -> Option<Version> {
+        self.derivative_id()?;
+        self.derivative_field("VERSION_ID", "DISTRIB_RELEASE").and_then(find_version)
+    }
+
+    /// Resolves the release that a derivative is based on from the
+    /// `os-release` `UBUNTU_CODENAME`, `DEBIAN_CODENAME` and `ID_LIKE`.
+    fn derivative_base(
+        &self,
+        derivative: Option<Derivative>,
+        codename: Option<&str>,
+    ) -> Base {
+        if let Some(codename) = self.os_release("UBUNTU_CODENAME") {
+            return Base::Ubuntu { release: self.release(None, &[codename], "ubuntu") };
+        }
+        if let Some(codename) = self.os_release("DEBIAN_CODENAME") {
+            return Base::Debian { release: self.release(None, &[codename], "debian") };
+        }
+        if self.is_like("ubuntu") {
+            return Base::Ubuntu { release: None };
+        }
+
+        // Devuan names its own releases, while Kali's `/etc/debian_version`
+        // reads `kali-rolling`
+        let devuan = match (derivative, codename) {
+            (Some(Derivative::Devuan), Some(codename)) => devuan_base(codename),
+            _ => None,
+        };
+        let release = match devuan {
+            Some(release) => Some(Release::Known(release)),
+            None => {
+                let version = self.read("etc/debian_version")
+                    .and_then(|s| find_version(&s));
+                self.release(version, &[], "debian")
+            },
+        };
+        Base::Debian { release }
+    }
+
+    /// Returns the suites of the host's APT sources, in both the one-line and
+    /// the deb822 formats.
+    fn apt_suites(&self) -> Vec<String> {
+        let mut paths = vec![self.root.join("etc/apt/sources.list")];
+        if let Ok(entries) = fs::read_dir(self.root.join("etc/apt/sources.list.d")) {
+            paths.extend(entries.filter_map(|entry| Some(entry.ok()?.path())));
+        }
+
+        let mut suites = Vec::new();
+        for path in paths {
+            let contents = match fs::read_to_string(&path) {
+                Ok(contents) => contents,
+                Err(_) => continue,
+            };
+            let is_deb822 = path.extension() == Some(OsStr::new("sources"));
+            for line in contents.lines().map(str::trim) {
+                if is_deb822 {
+                    let mut field = line.splitn(2, ':');
+                    if field.next() == Some("Suites") {
+                        let words = field.next().unwrap_or("").split_whitespace();
+                        suites.extend(words.map(String::from));
+                    }
+                } else if line.starts_with("deb") {
+                    // `deb [options] uri suite components...`
+                    let mut in_options = false;
+                    let mut words = line.split_whitespace().skip(1).filter(|word| {
+                        let skip = in_options || word.starts_with('[');
+                        in_options = skip && !word.ends_with(']');
+                        !skip
+                    });
+                    suites.extend(words.nth(1).map(String::from));
+                }
+            }
+        }
+        suites
+    }
+
+    /// Returns the release for `version`, or else for the first of `codenames`
+    /// that is known, like [`find_release`].
+    ///
+    /// Releases not known to this crate are looked up in the `distro-info`
+    /// table named `distro_info`.
+    fn release<R: KnownRelease>(
+        &self,
+        version: Option<Version>,
+        codenames: &[&str],
+        distro_info: &str,
+    ) -> Option<Release<R>> {
+        match find_release(version, codenames)? {
+            Release::Unknown(release) => {
+                Some(Release::Unknown(self.fill_unknown(release, distro_info)))
+            },
+            known => Some(known),
+        }
+    }
+
+    /// Fills in the name and lifecycle of `release` from the `distro-info`
+    /// table `name`, if the host has one that lists it.
+    fn fill_unknown(&self, release: UnknownRelease, name: &str) -> UnknownRelease {
+        let table = match DistroInfo::read_in(self.root, name) {
+            Ok(table) => table,
+            Err(_) => return release,
+        };
+        let record = release.codename.as_ref()
+            .and_then(|codename| table.find_series(codename))
+            .or_else(|| release.version.and_then(|version| table.find_version(version)));
+
+        match record {
+            Some(record) => UnknownRelease {
+                version: release.version.or(record.version),
+                codename: release.codename.or_else(|| Some(record.series.clone())),
+                ..record.unknown_release()
+            },
+            None => release,
+        }
+    }
+
+    /// Queries the distribution version.
+    fn distro_version(&self) -> Option<Version> {
+        let release_file = || {
+            RELEASE_FILES.iter()
+                .filter_map(|path| self.read(path))
+                .filter_map(|s| find_version(&s))
+                .next()
+        };
+
+        self.os_release("VERSION_ID")
+            .and_then(find_version)
+            .or_else(|| self.lsb_release("DISTRIB_RELEASE").and_then(find_version))
+            .or_else(release_file)
+            .or_else(|| self.cpe()?.version_number())
+    }
+
+    /// Queries the point release, which `VERSION_ID` lacks, such as `12.5` from
+    /// `/etc/debian_version` or `18.04.6` from the `os-release` `VERSION`.
+    fn point_release(&self) -> Option<Version> {
+        match self.id() {
+            Some("debian") | None => {
+                self.read("etc/debian_version").and_then(|s| find_version(&s))
+            },
+            Some("ubuntu") => {
+                self.os_release("VERSION")
+                    .or_else(|| self.lsb_release("DISTRIB_DESCRIPTION"))
+                    .and_then(find_version)
+            },
+            Some("android") => self.build_prop().release,
+            Some("alpine") if self.is_alpine_edge() => None,
+            Some("alpine") => {
+                self.read("etc/alpine-release").and_then(|s| find_version(&s))
+            },
+            Some("centos") if self.is_centos_stream() => None,
+            Some(id) if rhel::Distro::from_id(id).is_some() => {
+                self.read("etc/redhat-release")
+                    .and_then(|s| find_version(&s))
+                    .or_else(|| self.os_release("VERSION_ID").and_then(find_version))
+            },
+            Some(_) => None,
+        }
+    }
+
+    /// Queries the distribution version.
+    fn version(&self) -> Option<OsVersion> {
+        // Rolling releases have no version of their own, and their release
+        // files may hold that of a base package, such as Gentoo's baselayout
+        if self.id().and_then(rolling::Distro::from_id).is_some() {
+            return None;
+        }
+        self.derivative_version()
+            .or_else(|| self.point_release())
+            .or_else(|| self.distro_version())
+            .map(|Version { major, minor, patch }| OsVersion { major, minor, patch })
+    }
+
+    /// Queries whether the system is immutable, from the `os-release` `ID` and
+    /// `VARIANT_ID`, `/run/ostree-booted`, or the mount options of `/usr`.
+    fn immutable(&self) -> Option<Immutable> {
+        let variant_id = self.os_release("VARIANT_ID");
+        self.id()
+            .and_then(|id| Immutable::from_ids(id, variant_id))
+            .or_else(|| {
+                if self.root.join("run/ostree-booted").exists() {
+                    Some(Immutable::Ostree)
+                } else {
+                    None
+                }
+            })
+            .or_else(|| {
+                let mounts = self.read("proc/mounts")?;
+                if immutable::usr_is_read_only(&mounts) {
+                    Some(Immutable::ReadOnlyUsr)
+                } else {
+                    None
+                }
+            })
+    }
+
+    /// Queries the operating system that the host runs within.
+    fn guest(&self) -> Option<Guest> {
+        self.crostini().or_else(|| self.wsl())
+    }
+
+    fn crostini(&self) -> Option<Guest> {
+        let is_crostini = self.root.join("dev/.cros_milestone").exists() ||
+            self.root.join("opt/google/cros-containers").is_dir();
+        if !is_crostini {
+            return None;
+        }
+        let milestone = self.read("dev/.cros_milestone")
+            .and_then(|milestone| milestone.trim().parse().ok());
+        Some(Guest::Crostini { milestone })
+    }
+
+    fn wsl(&self) -> Option<Guest> {
+        let osrelease = self.read("proc/sys/kernel/osrelease").unwrap_or_default();
+        let interop = "proc/sys/fs/binfmt_misc/WSLInterop";
+        let version = WslVersion::from_kernel(&osrelease)
+            .or_else(|| WslVersion::from_kernel(&self.read("proc/version")?))
+            .or_else(|| {
+                // Recent WSL 2 kernels register interop after boot instead
+                if self.root.join(format!("{}-late", interop)).exists() {
+                    Some(WslVersion::Wsl2)
+                } else {
+                    None
+                }
+            });
+        if version.is_none() && !self.root.join(interop).exists() {
+            return None;
+        }
+
+        // WSL 1 reports the Windows build in place of the ABI number
+        let windows_build = match version {
+            Some(WslVersion::Wsl1) => {
+                let kernel: Option<KernelVersion> = osrelease.trim().parse().ok();
+                kernel.and_then(|kernel| kernel.abi)
+            },
+            _ => None,
+        };
+        let windows = windows_build
+            .and_then(windows::OsRelease::from_build)
+            .map(Release::Known);
+        Some(Guest::Wsl {
+            version,
+            distro_name: None,
+            windows_build,
+            windows,
+        })
+    }
+
+    /// Queries the version of the running kernel.
+    fn kernel(&self) -> Option<KernelVersion> {
+        self.read("proc/sys/kernel/osrelease")?.parse().ok()
+    }
+}
+
+/// Returns the release for `version`, or else for the first of `codenames`
+/// that is known.
+fn find_release<R: KnownRelease>(
+    version: Option<Version>,
+    codenames: &[&str],
+) -> Option<Release<R>> {
+    let known = version
+        .and_then(R::from_version)
+        .or_else(|| {
+            codenames.iter()
+                .filter_map(|codename| R::from_codename(codename))
+                .next()
+        });
+    Release::from_parts(known, version, codenames.first().cloned())
+}
+
+/// Returns the first version-like word in `s`, ignoring any trailing
+/// non-numeric parts such as in `4.15.0-45-generic` or `7.6.1810 (Core)`.
+fn find_version(s: &str) -> Option<Version> {
+    s.split_whitespace().filter_map(|word| {
+        let end = word
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(word.len());
+
+        let mut parts = word[..end]
+            .split('.')
+            .take(3)
+            .map(|part| part.parse::<u64>());
+
+        let major = parts.next()?.ok()?;
+        let minor = parts.next().and_then(Result::ok).unwrap_or(0);
+        let patch = parts.next().and_then(Result::ok).unwrap_or(0);
+
+        Some(Version { major, minor, patch })
+    }).next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use info::Lifecycle;
+    use date::Date;
+    use info::os::linux::{alpine, amazon, fedora};
+    use info::os::android;
+    use info::os::linux::fixtures::fixture;
+
+    type VersionTriple = (u64, u64, u64);
+
+    #[test]
+    fn find_versions() {
+        let pairs: &[(&str, Option<VersionTriple>)] = &[
+            ("18.04",                               Some((18, 4, 0))),
+            ("9.6\n",                               Some((9, 6, 0))),
+            ("4.15.0-45-generic",                   Some((4, 15, 0))),
+            ("6.1.0-rc3+",                          Some((6, 1, 0))),
+            ("CentOS Linux release 7.6.1810 (Core)", Some((7, 6, 1810))),
+            ("buster/sid",                          None),
+            ("",                                    None),
+        ];
+        for &(string, version) in pairs {
+            assert_eq!(find_version(string), version.map(Version::from));
+        }
+    }
+
+    #[test]
+    fn version_sources() {
+        let pairs: &[(&str, Option<VersionTriple>)] = &[
+            ("ubuntu-18.04",  Some((18, 4, 1))),
+            ("lsb-only",      Some((16, 4, 5))),
+            ("centos-7",      Some((7, 6, 1810))),
+            ("kernel-only",   None),
+            ("debian-9",      Some((9, 6, 0))),
+            ("debian-12",     Some((12, 5, 0))),
+            ("mint-21.3",     Some((21, 3, 0))),
+            ("mx-23",         Some((23, 1, 0))),
+            ("rhel-9",        Some((9, 3, 0))),
+            ("almalinux-9",   Some((9, 0, 0))),
+            ("amazon-2023",   Some((2023, 0, 0))),
+            ("sles-15-sp5",   Some((15, 5, 0))),
+            ("tumbleweed",    Some((20240101, 0, 0))),
+            ("alpine-3.12",   Some((3, 12, 12))),
+            ("alpine-edge",   Some((3, 21, 0))),
+            ("nixos-23.11",   Some((23, 11, 0))),
+            ("android-8.1",   Some((8, 1, 0))),
+        ];
+        for &(name, version) in pairs {
+            let info = Host::new(&fixture(name)).info();
+            let os_version = info.version.map(|version| *version.as_version());
+            assert_eq!(os_version, version.map(Version::from), "{}", name);
+        }
+    }
+
+    #[test]
+    fn detect_meta() {
+        let pairs = vec![
+            ("ubuntu-18.04", OsMeta::Ubuntu {
+                release: Some(ubuntu::OsRelease::BionicBeaver.into()),
+                point_release: Some(Version::new(18, 4, 1)),
+            }),
+            ("lsb-only", OsMeta::Ubuntu {
+                release: Some(ubuntu::OsRelease::XenialXerus.into()),
+                point_release: Some(Version::new(16, 4, 5)),
+            }),
+            ("ubuntu-codename", OsMeta::Ubuntu {
+                release: Some(ubuntu::OsRelease::CosmicCuttlefish.into()),
+                point_release: Some(Version::new(18, 10, 0)),
+            }),
+            ("ubuntu-24.04", OsMeta::Ubuntu {
+                release: Some(ubuntu::OsRelease::NobleNumbat.into()),
+                point_release: Some(Version::new(24, 4, 1)),
+            }),
+            ("ubuntu-core-22", OsMeta::Ubuntu {
+                release: Some(ubuntu::OsRelease::JammyJellyfish.into()),
+                point_release: None,
+            }),
+            ("ubuntu-26.04", OsMeta::Ubuntu {
+                release: Some(ubuntu::OsRelease::ResoluteRaccoon.into()),
+                point_release: Some(Version::new(26, 4, 0)),
+            }),
+            ("ubuntu-4.10", OsMeta::Ubuntu {
+                release: Some(Release::Unknown(UnknownRelease {
+                    version: Some(Version::new(4, 10, 0)),
+                    codename: Some("warty".to_owned()),
+                    name: Some("Warty Warthog".to_owned()),
+                    lifecycle: Some(Lifecycle {
+                        released: Date::new(2004, 10, 20),
+                        support_end: Some(Date::new(2006, 4, 30)),
+                        extended_end: None,
+                    }),
+                    support_end: None,
+                })),
+                point_release: Some(Version::new(4, 10, 0)),
+            }),
+            ("debian-9", OsMeta::Debian {
+                release: Some(debian::OsRelease::Stretch.into()),
+                point_release: Some(Version::new(9, 6, 0)),
+                suite: Some(debian::Suite::Stable),
+            }),
+            ("debian-6", OsMeta::Debian {
+                release: Some(debian::OsRelease::Squeeze.into()),
+                point_release: Some(Version::new(6, 0, 10)),
+                suite: Some(debian::Suite::Stable),
+            }),
+            ("debian-12", OsMeta::Debian {
+                release: Some(debian::OsRelease::Bookworm.into()),
+                point_release: Some(Version::new(12, 5, 0)),
+                suite: Some(debian::Suite::Stable),
+            }),
+            ("debian-testing", OsMeta::Debian {
+                release: Some(debian::OsRelease::Trixie.into()),
+                point_release: None,
+                suite: Some(debian::Suite::Testing),
+            }),
+            ("debian-sid", OsMeta::Debian {
+                release: Some(Release::Unknown(UnknownRelease {
+                    codename: Some("forky".to_owned()),
+                    ..Default::default()
+                })),
+                point_release: None,
+                suite: Some(debian::Suite::Unstable),
+            }),
+            ("mint-21.3", OsMeta::Derivative {
+                derivative: Some(Derivative::LinuxMint),
+                id: "linuxmint".to_owned(),
+                name: Some("Linux Mint".to_owned()),
+                version: Some(Version::new(21, 3, 0)),
+                codename: Some("virginia".to_owned()),
+                base: Base::Ubuntu {
+                    release: Some(ubuntu::OsRelease::JammyJellyfish.into()),
+                },
+            }),
+            ("lmde-6", OsMeta::Derivative {
+                derivative: Some(Derivative::LinuxMint),
+                id: "linuxmint".to_owned(),
+                name: Some("LMDE".to_owned()),
+                version: Some(Version::new(6, 0, 0)),
+                codename: Some("faye".to_owned()),
+                base: Base::Debian { release: Some(debian::OsRelease::Bookworm.into()) },
+            }),
+            ("raspios-12", OsMeta::Derivative {
+                derivative: Some(Derivative::Raspbian),
+                id: "raspbian".to_owned(),
+                name: Some("Raspberry Pi OS".to_owned()),
+                version: Some(Version::new(12, 0, 0)),
+                codename: Some("bookworm".to_owned()),
+                base: Base::Debian { release: Some(debian::OsRelease::Bookworm.into()) },
+            }),
+            ("kali-rolling", OsMeta::Derivative {
+                derivative: Some(Derivative::Kali),
+                id: "kali".to_owned(),
+                name: Some("Kali GNU/Linux".to_owned()),
+                version: Some(Version::new(2024, 1, 0)),
+                codename: Some("kali-rolling".to_owned()),
+                base: Base::Debian { release: None },
+            }),
+            ("devuan-5", OsMeta::Derivative {
+                derivative: Some(Derivative::Devuan),
+                id: "devuan".to_owned(),
+                name: Some("Devuan GNU/Linux".to_owned()),
+                version: Some(Version::new(5, 0, 0)),
+                codename: Some("daedalus".to_owned()),
+                base: Base::Debian { release: Some(debian::OsRelease::Bookworm.into()) },
+            }),
+            ("mx-23", OsMeta::Derivative {
+                derivative: Some(Derivative::Mx),
+                id: "mx".to_owned(),
+                name: Some("MX Linux".to_owned()),
+                version: Some(Version::new(23, 1, 0)),
+                codename: Some("libretto".to_owned()),
+                base: Base::Debian { release: Some(debian::OsRelease::Bookworm.into()) },
+            }),
+            ("centos-7", OsMeta::Rhel {
+                distro: rhel::Distro::CentOs,
+                release: Some(rhel::OsRelease::Rhel7.into()),
+                point_release: Some(Version::new(7, 6, 1810)),
+            }),
+            ("centos-stream-9", OsMeta::Rhel {
+                distro: rhel::Distro::CentOsStream,
+                release: Some(rhel::OsRelease::Rhel9.into()),
+                point_release: None,
+            }),
+            ("rhel-9", OsMeta::Rhel {
+                distro: rhel::Distro::Rhel,
+                release: Some(rhel::OsRelease::Rhel9.into()),
+                point_release: Some(Version::new(9, 3, 0)),
+            }),
+            ("rocky-8", OsMeta::Rhel {
+                distro: rhel::Distro::Rocky,
+                release: Some(rhel::OsRelease::Rhel8.into()),
+                point_release: Some(Version::new(8, 9, 0)),
+            }),
+            ("oracle-8", OsMeta::Rhel {
+                distro: rhel::Distro::Oracle,
+                release: Some(rhel::OsRelease::Rhel8.into()),
+                point_release: Some(Version::new(8, 9, 0)),
+            }),
+            ("almalinux-9", OsMeta::Rhel {
+                distro: rhel::Distro::Alma,
+                release: Some(rhel::OsRelease::Rhel9.into()),
+                point_release: None,
+            }),
+            ("fedora-39", OsMeta::Fedora {
+                release: Some(fedora::OsRelease::Fedora39.into()),
+            }),
+            ("fedora-45", OsMeta::Fedora {
+                release: Some(Release::Unknown(UnknownRelease {
+                    version: Some(Version::new(45, 0, 0)),
+                    support_end: Some(Date::new(2027, 12, 8)),
+                    ..Default::default()
+                })),
+            }),
+            ("amazon-2023", OsMeta::AmazonLinux {
+                release: Some(amazon::OsRelease::AmazonLinux2023.into()),
+            }),
+            ("leap-15.5", OsMeta::Suse {
+                distro: suse::Distro::Leap,
+                version: Some(suse::SuseVersion::Release(Version::new(15, 5, 0))),
+                release: Some(suse::OsRelease::Sle15Sp5.into()),
+            }),
+            ("leap-42.3", OsMeta::Suse {
+                distro: suse::Distro::Leap,
+                version: Some(suse::SuseVersion::Release(Version::new(42, 3, 0))),
+                release: Some(suse::OsRelease::Sle12Sp3.into()),
+            }),
+            ("tumbleweed", OsMeta::Suse {
+                distro: suse::Distro::Tumbleweed,
+                version: Some(suse::SuseVersion::Snapshot(Date::new(2024, 1, 1))),
+                release: None,
+            }),
+            ("tumbleweed-2017", OsMeta::Suse {
+                distro: suse::Distro::Tumbleweed,
+                version: Some(suse::SuseVersion::Snapshot(Date::new(2017, 1, 1))),
+                release: None,
+            }),
+            ("sles-15-sp5", OsMeta::Suse {
+                distro: suse::Distro::Sles,
+                version: Some(suse::SuseVersion::ServicePack {
+                    major: 15,
+                    service_pack: 5,
+                }),
+                release: Some(suse::OsRelease::Sle15Sp5.into()),
+            }),
+            ("alpine-3.19", OsMeta::Alpine {
+                release: Some(alpine::OsRelease::V3_19.into()),
+                point_release: Some(Version::new(3, 19, 1)),
+                edge: false,
+            }),
+            ("alpine-3.12", OsMeta::Alpine {
+                release: Some(alpine::OsRelease::V3_12.into()),
+                point_release: Some(Version::new(3, 12, 12)),
+                edge: false,
+            }),
+            ("alpine-edge", OsMeta::Alpine {
+                release: Some(alpine::OsRelease::V3_21.into()),
+                point_release: None,
+                edge: true,
+            }),
+            ("arch", OsMeta::Rolling {
+                distro: rolling::Distro::Arch,
+                snapshot: rolling::Snapshot {
+                    date: Some(Date::new(2024, 1, 1)),
+                    build_id: Some("rolling".to_owned()),
+                    ..Default::default()
+                },
+            }),
+            ("manjaro", OsMeta::Rolling {
+                distro: rolling::Distro::Manjaro,
+                snapshot: rolling::Snapshot {
+                    build_id: Some("rolling".to_owned()),
+                    branch: Some(rolling::Branch::Testing),
+                    ..Default::default()
+                },
+            }),
+            ("gentoo", OsMeta::Rolling {
+                distro: rolling::Distro::Gentoo,
+                snapshot: rolling::Snapshot {
+                    profile: Some("default/linux/amd64/23.0/systemd".to_owned()),
+                    ..Default::default()
+                },
+            }),
+            ("void", OsMeta::Rolling {
+                distro: rolling::Distro::Void,
+                snapshot: rolling::Snapshot::default(),
+            }),
+            ("nixos-23.11", OsMeta::NixOs {
+                release: Some(nixos::OsRelease::Tapir.into()),
+                version: Some(nixos::NixVersion {
+                    release: Version::new(23, 11, 0),
+                    pre_release: false,
+                    date: Some(Date::new(2024, 1, 1)),
+                    revision: Some("abcdef0".to_owned()),
+                }),
+                generation: Some(123),
+                channel: Some("nixos-23.11".to_owned()),
+            }),
+            ("android-14", OsMeta::Android {
+                release: Some(android::OsRelease::Android14.into()),
+                api_level: Some(34),
+                security_patch: Some(Date::new(2024, 1, 5)),
+                termux_prefix: Some(PathBuf::from("/data/data/com.termux/files/usr")),
+            }),
+            ("android-8.1", OsMeta::Android {
+                release: Some(android::OsRelease::Oreo.into()),
+                api_level: Some(27),
+                security_patch: Some(Date::new(2018, 12, 5)),
+                termux_prefix: None,
+            }),
+            ("chromeos-120", OsMeta::ChromeOs {
+                milestone: Some(120),
+                platform_version: Some(Version::new(15662, 76, 0)),
+                channel: Some(chromeos::Channel::Stable),
+                board: Some("octopus".to_owned()),
+            }),
+            ("kernel-only", OsMeta::UnknownLinux { id: None, name: None }),
+        ];
+        for (name, meta) in pairs {
+            assert_eq!(Host::new(&fixture(name)).info().meta, meta, "{}", name);
+        }
+    }
+
+    #[test]
+    fn rolling_releases() {
+        let pairs = [
+            ("arch",           true,  Some(Date::new(2024, 1, 1))),
+            ("tumbleweed",     true,  Some(Date::new(2024, 1, 1))),
+            ("gentoo",         true,  None),
+            ("debian-sid",     true,  None),
+            ("alpine-edge",    true,  None),
+            ("debian-12",      false, None),
+            ("leap-15.5",      false, None),
+        ];
+        for &(name, is_rolling, date) in &pairs {
+            let meta = Host::new(&fixture(name)).info().meta;
+            assert_eq!(meta.is_rolling(), is_rolling, "{}", name);
+            assert_eq!(meta.snapshot_date(), date, "{}", name);
+        }
+
+        let info = Host::new(&fixture("arch")).info();
+        assert!(info.kernel.is_some());
+        assert_eq!(info.version, None);
+        for &name in &["void", "gentoo", "manjaro"] {
+            assert_eq!(Host::new(&fixture(name)).info().version, None, "{}", name);
+        }
+    }
+
+    #[test]
+    fn immutable_systems() {
+        let pairs = [
+            ("silverblue-39",  Some(Immutable::Ostree)),
+            ("microos",        Some(Immutable::Transactional)),
+            ("ubuntu-core-22", Some(Immutable::UbuntuCore)),
+            ("readonly-usr",   Some(Immutable::ReadOnlyUsr)),
+            ("fedora-39",      None),
+            ("debian-12",      None),
+        ];
+        for &(name, immutable) in &pairs {
+            let info = Host::new(&fixture(name)).info();
+            assert_eq!(info.immutable, immutable, "{}", name);
+        }
+    }
+
+    #[test]
+    fn guests() {
+        let info = Host::new(&fixture("crostini-12")).info();
+        assert_eq!(info.guest, Some(Guest::Crostini { milestone: Some(120) }));
+        assert_eq!(info.meta.release_name(), Some("Bookworm"));
+
+        let info = Host::new(&fixture("wsl1")).info();
+        assert_eq!(info.guest, Some(Guest::Wsl {
+            version: Some(WslVersion::Wsl1),
+            distro_name: None,
+            windows_build: Some(19041),
+            windows: Some(windows::OsRelease::Windows10.into()),
+        }));
+
+        let info = Host::new(&fixture("wsl2")).info();
+        assert_eq!(info.guest, Some(Guest::Wsl {
+            version: Some(WslVersion::Wsl2),
+            distro_name: None,
+            windows_build: None,
+            windows: None,
+        }));
+        assert_eq!(info.meta.release_name(), Some("Noble Numbat"));
+
+        assert_eq!(Host::new(&fixture("debian-12")).info().guest, None);
+        assert_eq!(Host::new(&fixture("chromeos-120")).info().guest, None);
+    }
+
+    #[test]
+    fn fhs() {
+        assert!(Host::new(&fixture("debian-12")).info().meta.has_fhs());
+        assert!(!Host::new(&fixture("nixos-23.11")).info().meta.has_fhs());
+    }
+
+    #[test]
+    fn lifecycles() {
+        use info::SupportStatus;
+
+        let on = Date::new(2024, 7, 1);
+        let pairs = [
+            ("centos-7",  Some(SupportStatus::EndOfLife)),
+            ("rhel-9",    Some(SupportStatus::Supported)),
+            ("debian-12", Some(SupportStatus::Supported)),
+            ("leap-15.5", Some(SupportStatus::Supported)),
+            ("leap-42.3", Some(SupportStatus::EndOfLife)),
+            ("fedora-45", Some(SupportStatus::Supported)),
+            ("arch",      None),
+        ];
+        for &(name, status) in &pairs {
+            let meta = Host::new(&fixture(name)).info().meta;
+            assert_eq!(meta.support_status(on), status, "{}", name);
+        }
+
+        let rhel7 = rhel::OsRelease::Rhel7.lifecycle();
+        assert_eq!(rhel7.status(on), SupportStatus::Extended);
+
+        let meta = Host::new(&fixture("fedora-45")).info().meta;
+        assert_eq!(meta.lifecycle(), None);
+        let on = Date::new(2027, 12, 9);
+        assert_eq!(meta.support_status(on), Some(SupportStatus::EndOfLife));
+
+        let on = Date::new(2019, 7, 1);
+        let meta = Host::new(&fixture("leap-42.3")).info().meta;
+        assert_eq!(meta.support_status(on), Some(SupportStatus::Supported));
+        let sle12sp3 = suse::OsRelease::Sle12Sp3.lifecycle();
+        assert_eq!(sle12sp3.status(on), SupportStatus::Extended);
+    }
+}
@@ -0,0 +1,113 @@
+//! Immutable and image-based distributions, whose `/usr` is read-only and
+//! which are updated as a whole rather than package by package.
+
+/// The way an immutable Linux system is built and updated, which determines
+/// how software can be installed on it.
+///
+/// Installing into `/usr` on such a system fails with `EROFS`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Immutable {
+    /// A system deployed with OSTree, such as Fedora Silverblue, Kinoite,
+    /// CoreOS or Endless OS.
+    ///
+    /// Packages can be layered onto the image with `rpm-ostree install` on
+    /// Fedora-based systems, and otherwise installed with Flatpak or in a
+    /// container.
+    Ostree,
+    /// openSUSE MicroOS or one of its desktops, whose Btrfs snapshots are
+    /// changed with `transactional-update pkg install`.
+    Transactional,
+    /// Ubuntu Core, where all software is installed as snaps.
+    UbuntuCore,
+    /// SteamOS, whose image is replaced on each update, discarding packages
+    /// installed into it.
+    SteamOs,
+    /// Another system whose `/usr` is mounted read-only.
+    ReadOnlyUsr,
+}
+
+impl Immutable {
+    /// Returns the system for the lower-case `os-release` `ID` and
+    /// `VARIANT_ID`, such as `fedora` and `silverblue`, if they identify an
+    /// immutable distribution.
+    pub fn from_ids(id: &str, variant_id: Option<&str>) -> Option<Immutable> {
+        match (id, variant_id.unwrap_or_default()) {
+            ("ubuntu-core", _) => Some(Immutable::UbuntuCore),
+            ("steamos", _) => Some(Immutable::SteamOs),
+            ("endless", _) |
+            ("fedora-coreos", _) |
+            ("rhcos", _) |
+            (_, "silverblue") |
+            (_, "kinoite") |
+            (_, "sericea") |
+            (_, "onyx") |
+            (_, "coreos") |
+            (_, "iot") |
+            (_, "atomic") => Some(Immutable::Ostree),
+            ("opensuse-microos", _) |
+            ("opensuse-aeon", _) |
+            ("opensuse-kalpa", _) |
+            ("sl-micro", _) |
+            ("suse-microos", _) => Some(Immutable::Transactional),
+            _ => None,
+        }
+    }
+}
+
+/// Returns whether `/usr` is mounted read-only according to the contents of
+/// `/proc/mounts`.
+///
+/// If `/usr` is not a mount point of its own, the root file system is used.
+/// The last entry for a mount point takes precedence, as it is mounted over
+/// the others.
+pub fn usr_is_read_only(mounts: &str) -> bool {
+    let options = |target: &str| {
+        mounts.lines()
+            .rev()
+            .find_map(|line| {
+                let mut fields = line.split_whitespace();
+                let (_source, mount_point) = (fields.next()?, fields.next()?);
+                let (_fs_type, options) = (fields.next()?, fields.next()?);
+                if mount_point == target { Some(options) } else { None }
+            })
+    };
+    options("/usr")
+        .or_else(|| options("/"))
+        .map(|options| options.split(',').any(|option| option == "ro"))
+        == Some(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids() {
+        let pairs = [
+            ("fedora",           Some("silverblue"),  Some(Immutable::Ostree)),
+            ("fedora",           Some("workstation"), None),
+            ("opensuse-microos", None,                Some(Immutable::Transactional)),
+            ("ubuntu-core",      None,                Some(Immutable::UbuntuCore)),
+            ("ubuntu",           None,                None),
+        ];
+        for &(id, variant_id, immutable) in &pairs {
+            assert_eq!(Immutable::from_ids(id, variant_id), immutable, "{}", id);
+        }
+    }
+
+    #[test]
+    fn read_only_usr() {
+        let mounts = "\
+            /dev/sda3 / btrfs rw,relatime 0 0\n\
+            /dev/sda3 /usr btrfs ro,relatime 0 0\n";
+        assert!(usr_is_read_only(mounts));
+
+        let mounts = "\
+            overlay / overlay ro,relatime 0 0\n\
+            overlay / overlay rw,relatime 0 0\n";
+        assert!(!usr_is_read_only(mounts));
+        assert!(usr_is_read_only("/dev/root / ext4 ro,noatime 0 0\n"));
+        assert!(!usr_is_read_only(""));
+    }
+}
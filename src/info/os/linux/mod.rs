@@ -1,15 +1,32 @@
 //! Utilities specific to Linux.
 
-use version::OsVersion;
-use info::{OsInfo, OsMeta};
-
+pub mod alpine;
+pub mod amazon;
+pub mod chromeos;
 pub mod debian;
+pub mod derivative;
+pub mod distro_info;
+pub mod fedora;
+pub mod guest;
+pub mod immutable;
+pub mod nixos;
+pub mod os_release;
+pub mod rhel;
+pub mod rolling;
+pub mod suse;
 pub mod ubuntu;
 
-pub(crate) fn get_info() -> OsInfo {
-    let version = OsVersion::get();
-    OsInfo {
-        meta: OsMeta::UnknownLinux {},
-        version,
-    }
-}
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod host;
+
+#[cfg(test)]
+mod fixtures;
+
+pub use self::derivative::{Base, Derivative};
+pub use self::distro_info::{DistroInfo, DistroInfoRecord};
+pub use self::guest::{Guest, WslVersion};
+pub use self::immutable::Immutable;
+pub use self::os_release::OsReleaseFile;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) use self::host::{get_info, get_version};
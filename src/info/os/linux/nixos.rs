@@ -0,0 +1,242 @@
+//! Utilities specific to NixOS.
+
+use std::str::FromStr;
+
+use date::Date;
+use version::{ParseVersionError, Version};
+
+releases! {
+    /// The release name of a known NixOS stable release.
+    ///
+    /// Standard support ends with the last update to the release channel, a
+    /// month after the next release.
+    pub enum OsRelease: "NixOS" {
+        /// NixOS 20.03 (Markhor).
+        Markhor {
+            version: (20, 3),
+            name: "20.03",
+            codename: "markhor",
+            lifecycle: [(2020, 04, 20), (2020, 10, 31), -],
+        }
+
+        /// NixOS 20.09 (Nightingale).
+        Nightingale {
+            version: (20, 9),
+            name: "20.09",
+            codename: "nightingale",
+            lifecycle: [(2020, 10, 27), (2021, 06, 30), -],
+        }
+
+        /// NixOS 21.05 (Okapi).
+        Okapi {
+            version: (21, 5),
+            name: "21.05",
+            codename: "okapi",
+            lifecycle: [(2021, 05, 31), (2021, 12, 31), -],
+        }
+
+        /// NixOS 21.11 (Porcupine).
+        Porcupine {
+            version: (21, 11),
+            name: "21.11",
+            codename: "porcupine",
+            lifecycle: [(2021, 11, 30), (2022, 06, 30), -],
+        }
+
+        /// NixOS 22.05 (Quokka).
+        Quokka {
+            version: (22, 5),
+            name: "22.05",
+            codename: "quokka",
+            lifecycle: [(2022, 05, 30), (2022, 12, 31), -],
+        }
+
+        /// NixOS 22.11 (Raccoon).
+        Raccoon {
+            version: (22, 11),
+            name: "22.11",
+            codename: "raccoon",
+            lifecycle: [(2022, 11, 30), (2023, 06, 30), -],
+        }
+
+        /// NixOS 23.05 (Stoat).
+        Stoat {
+            version: (23, 5),
+            name: "23.05",
+            codename: "stoat",
+            lifecycle: [(2023, 05, 31), (2023, 12, 31), -],
+        }
+
+        /// NixOS 23.11 (Tapir).
+        Tapir {
+            version: (23, 11),
+            name: "23.11",
+            codename: "tapir",
+            lifecycle: [(2023, 11, 29), (2024, 06, 30), -],
+        }
+
+        /// NixOS 24.05 (Uakari).
+        Uakari {
+            version: (24, 5),
+            name: "24.05",
+            codename: "uakari",
+            lifecycle: [(2024, 05, 31), (2024, 12, 31), -],
+        }
+
+        /// NixOS 24.11 (Vicuña).
+        Vicuna {
+            version: (24, 11),
+            name: "24.11",
+            codename: "vicuna",
+            lifecycle: [(2024, 11, 30), (2025, 06, 30), -],
+        }
+
+        /// NixOS 25.05 (Warbler).
+        Warbler {
+            version: (25, 5),
+            name: "25.05",
+            codename: "warbler",
+            lifecycle: [(2025, 05, 23), (2025, 12, 31), -],
+        }
+
+        /// NixOS 25.11 (Xantusia).
+        Xantusia {
+            version: (25, 11),
+            name: "25.11",
+            codename: "xantusia",
+            lifecycle: [(2025, 11, 30), (2026, 06, 30), -],
+        }
+    }
+}
+
+/// A NixOS version, such as `23.11.20240101.abcdef0`.
+///
+/// The version is made of the release, the date of the last commit to Nixpkgs
+/// and the abbreviated Git revision of that commit. Versions before 21.11
+/// have a commit count in place of the date, such as `20.09.4321.abcdef0`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NixVersion {
+    /// The release, such as `23.11`.
+    pub release: Version,
+    /// Whether the version precedes the release, as on the unstable channel
+    /// with versions like `24.05pre571216.abcdef0`.
+    pub pre_release: bool,
+    /// The date of the Nixpkgs commit, if the version has one.
+    pub date: Option<Date>,
+    /// The abbreviated Git revision of the Nixpkgs commit, such as `abcdef0`.
+    pub revision: Option<String>,
+}
+
+impl NixVersion {
+    /// Parses a version like `23.11.20240101.abcdef0`, ignoring a trailing
+    /// codename like ` (Tapir)` as in the `os-release` `VERSION`.
+    #[inline]
+    pub fn parse(version: &str) -> Result<NixVersion, ParseVersionError> {
+        version.parse()
+    }
+}
+
+impl FromStr for NixVersion {
+    type Err = ParseVersionError;
+
+    fn from_str(s: &str) -> Result<NixVersion, ParseVersionError> {
+        let s = s.split_whitespace().next().ok_or(ParseVersionError::EmptyInput)?;
+        let mut parts = s.splitn(3, '.');
+        let major = parts.next().unwrap_or_default();
+        let minor = parts.next().unwrap_or_default();
+        let rest = parts.next().unwrap_or_default();
+
+        let (minor, build, revision, pre_release) = match minor.find("pre") {
+            Some(i) => (&minor[..i], &minor[(i + "pre".len())..], rest, true),
+            None => {
+                let mut rest = rest.splitn(2, '.');
+                let build = rest.next().unwrap_or_default();
+                (minor, build, rest.next().unwrap_or_default(), false)
+            },
+        };
+
+        Ok(NixVersion {
+            release: Version {
+                major: major.parse().map_err(ParseVersionError::MajorInt)?,
+                minor: minor.parse().map_err(ParseVersionError::MinorInt)?,
+                patch: 0,
+            },
+            pre_release,
+            date: Date::parse_compact(build).ok(),
+            revision: Some(revision)
+                .filter(|revision| !revision.is_empty())
+                .map(ToOwned::to_owned),
+        })
+    }
+}
+
+/// Returns the number of the system generation that the profile symlink
+/// `target` points to, such as 123 for `system-123-link`.
+pub fn generation(target: &str) -> Option<u64> {
+    let name = target.rsplit('/').next()?;
+    if !name.starts_with("system-") || !name.ends_with("-link") {
+        return None;
+    }
+    name.get("system-".len()..name.len().saturating_sub("-link".len()))?
+        .parse()
+        .ok()
+}
+
+/// Returns the channel that the `nixos` channel subscribes to, such as
+/// `nixos-23.11`, from the contents of `~/.nix-channels`.
+pub fn channel(nix_channels: &str) -> Option<String> {
+    nix_channels.lines().find_map(|line| {
+        let mut fields = line.split_whitespace();
+        let url = fields.next()?;
+        if fields.next()? != "nixos" {
+            return None;
+        }
+        url.trim_end_matches('/').rsplit('/').next().map(ToOwned::to_owned)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_version() {
+        let version = NixVersion::parse("23.11.20240101.abcdef0 (Tapir)").unwrap();
+        assert_eq!(version, NixVersion {
+            release: Version::new(23, 11, 0),
+            pre_release: false,
+            date: Some(Date::new(2024, 1, 1)),
+            revision: Some("abcdef0".to_owned()),
+        });
+        assert_eq!(OsRelease::new(version.release), Some(OsRelease::Tapir));
+
+        let version = NixVersion::parse("20.09.4321.abcdef0").unwrap();
+        assert_eq!(version.date, None);
+        assert_eq!(version.revision.as_deref(), Some("abcdef0"));
+
+        let version = NixVersion::parse("24.05pre571216.abcdef0").unwrap();
+        assert!(version.pre_release);
+        assert_eq!(version.release, Version::new(24, 5, 0));
+        assert_eq!(version.revision.as_deref(), Some("abcdef0"));
+
+        assert_eq!(NixVersion::parse("23.11").unwrap().revision, None);
+        assert!(NixVersion::parse("tapir").is_err());
+    }
+
+    #[test]
+    fn generations() {
+        assert_eq!(generation("system-123-link"), Some(123));
+        assert_eq!(generation("/nix/var/nix/profiles/system-7-link"), Some(7));
+        assert_eq!(generation("/nix/store/abc-nixos-system"), None);
+        assert_eq!(generation("system-link"), None);
+    }
+
+    #[test]
+    fn channels() {
+        let contents = "\
+            https://nixos.org/channels/nixpkgs-unstable nixpkgs\n\
+            https://nixos.org/channels/nixos-23.11/ nixos\n";
+        assert_eq!(channel(contents).as_deref(), Some("nixos-23.11"));
+        assert_eq!(channel("https://nixos.org/channels/nixos-23.11"), None);
+    }
+}
@@ -0,0 +1,265 @@
+//! Parsing of the [`os-release`](https://www.freedesktop.org/software/systemd/man/os-release.html)
+//! file format.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use date::Date;
+
+/// The paths searched for an `os-release` file, in order of preference.
+pub const PATHS: &[&str] = &["/etc/os-release", "/usr/lib/os-release"];
+
+/// The contents of an `os-release` file.
+///
+/// Values are unquoted and unescaped as described by the specification. Keys
+/// without a dedicated field are kept in [`extra`](#structfield.extra).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OsReleaseFile {
+    /// `ID`: a lower-case identifier for the operating system, such as
+    /// `ubuntu` or `debian`.
+    pub id: Option<String>,
+    /// `ID_LIKE`: identifiers of closely related operating systems, ordered
+    /// from most to least related.
+    pub id_like: Vec<String>,
+    /// `NAME`: the operating system name, such as `Ubuntu`.
+    pub name: Option<String>,
+    /// `PRETTY_NAME`: a name suitable for presentation to the user, such as
+    /// `Ubuntu 18.04.1 LTS`.
+    pub pretty_name: Option<String>,
+    /// `VERSION`: the operating system version suitable for presentation to
+    /// the user, such as `18.04.1 LTS (Bionic Beaver)`.
+    pub version: Option<String>,
+    /// `VERSION_ID`: a version identifier suitable for processing, such as
+    /// `18.04`.
+    pub version_id: Option<String>,
+    /// `VERSION_CODENAME`: a lower-case release codename, such as `bionic`.
+    pub version_codename: Option<String>,
+    /// `BUILD_ID`: an identifier for the system image, commonly used by
+    /// rolling releases.
+    pub build_id: Option<String>,
+    /// `VARIANT_ID`: a lower-case identifier for the edition of the operating
+    /// system, such as `server` or `silverblue`.
+    pub variant_id: Option<String>,
+    /// `SUPPORT_END`: the date at which support for this version ends, in
+    /// `YYYY-MM-DD` format.
+    pub support_end: Option<String>,
+    /// All other keys and their values.
+    pub extra: BTreeMap<String, String>,
+}
+
+impl OsReleaseFile {
+    /// Reads the host's `os-release` file from `/etc/os-release`, falling back
+    /// to `/usr/lib/os-release`.
+    pub fn read() -> io::Result<OsReleaseFile> {
+        Self::read_in(Path::new("/"))
+    }
+
+    /// Reads and parses the `os-release` file at `path`.
+    pub fn read_from<P: AsRef<Path>>(path: P) -> io::Result<OsReleaseFile> {
+        fs::read_to_string(path).map(|s| Self::parse(&s))
+    }
+
+    pub(crate) fn read_in(root: &Path) -> io::Result<OsReleaseFile> {
+        let mut result = Err(io::ErrorKind::NotFound.into());
+        for path in PATHS {
+            result = Self::read_from(root.join(&path[1..]));
+            if result.is_ok() {
+                break;
+            }
+        }
+        result
+    }
+
+    /// Parses the contents of an `os-release` file.
+    ///
+    /// Lines that are not valid assignments are ignored, as are empty lines
+    /// and comments.
+    pub fn parse(contents: &str) -> OsReleaseFile {
+        let mut file = OsReleaseFile::default();
+        for (key, value) in assignments(contents) {
+            let field = match key.as_str() {
+                "ID"               => &mut file.id,
+                "NAME"             => &mut file.name,
+                "PRETTY_NAME"      => &mut file.pretty_name,
+                "VERSION"          => &mut file.version,
+                "VERSION_ID"       => &mut file.version_id,
+                "VERSION_CODENAME" => &mut file.version_codename,
+                "BUILD_ID"         => &mut file.build_id,
+                "VARIANT_ID"       => &mut file.variant_id,
+                "SUPPORT_END"      => &mut file.support_end,
+                "ID_LIKE" => {
+                    file.id_like = value
+                        .split_whitespace()
+                        .map(ToOwned::to_owned)
+                        .collect();
+                    continue;
+                },
+                _ => {
+                    file.extra.insert(key, value);
+                    continue;
+                },
+            };
+            *field = Some(value);
+        }
+        file
+    }
+
+    /// Returns the value for `key`, whether or not it has a dedicated field.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        let field = match key {
+            "ID"               => &self.id,
+            "NAME"             => &self.name,
+            "PRETTY_NAME"      => &self.pretty_name,
+            "VERSION"          => &self.version,
+            "VERSION_ID"       => &self.version_id,
+            "VERSION_CODENAME" => &self.version_codename,
+            "BUILD_ID"         => &self.build_id,
+            "VARIANT_ID"       => &self.variant_id,
+            "SUPPORT_END"      => &self.support_end,
+            _ => return self.extra.get(key).map(String::as_str),
+        };
+        field.as_ref().map(String::as_str)
+    }
+
+    /// Returns the `SUPPORT_END` date, if it is a valid `YYYY-MM-DD` date.
+    pub fn support_end_date(&self) -> Option<Date> {
+        self.support_end.as_ref()?.parse().ok()
+    }
+
+    /// Returns whether `ID` or any of `ID_LIKE` is `id`.
+    pub fn is_like(&self, id: &str) -> bool {
+        self.id.iter().chain(&self.id_like).any(|own| own == id)
+    }
+}
+
+/// Returns the `KEY=value` assignments in a shell-compatible file, such as
+/// `os-release` or `/etc/lsb-release`.
+///
+/// Values may be quoted with single or double quotes and contain backslash
+/// escapes, as in a POSIX shell. Variable expansion is not performed.
+pub(crate) fn assignments(contents: &str) -> Vec<(String, String)> {
+    contents.lines().filter_map(|line| {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+        let eq = line.find('=')?;
+        let (key, value) = (&line[..eq], &line[(eq + 1)..]);
+
+        let mut chars = key.chars();
+        let valid_key = match chars.next() {
+            Some(c) => {
+                (c.is_ascii_alphabetic() || c == '_') &&
+                chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+            },
+            None => false,
+        };
+
+        if valid_key {
+            Some((key.to_owned(), unquote(value)?))
+        } else {
+            None
+        }
+    }).collect()
+}
+
+/// Removes shell-style quoting and escapes from `value`, returning `None` if a
+/// quote is left unterminated.
+fn unquote(value: &str) -> Option<String> {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => loop {
+                match chars.next()? {
+                    '\'' => break,
+                    c    => result.push(c),
+                }
+            },
+            '"' => loop {
+                match chars.next()? {
+                    '"' => break,
+                    '\\' => match chars.next()? {
+                        c @ '"' | c @ '\\' | c @ '$' | c @ '`' => result.push(c),
+                        c => {
+                            result.push('\\');
+                            result.push(c);
+                        },
+                    },
+                    c => result.push(c),
+                }
+            },
+            '\\' => result.push(chars.next()?),
+            // Unquoted whitespace ends the value, as in a shell assignment
+            c if c.is_whitespace() => break,
+            c => result.push(c),
+        }
+    }
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UBUNTU: &str = "\
+NAME=\"Ubuntu\"
+VERSION=\"18.04.1 LTS (Bionic Beaver)\"
+ID=ubuntu
+ID_LIKE=debian
+PRETTY_NAME=\"Ubuntu 18.04.1 LTS\"
+VERSION_ID=\"18.04\"
+HOME_URL=\"https://www.ubuntu.com/\"
+VERSION_CODENAME=bionic
+UBUNTU_CODENAME=bionic
+";
+
+    #[test]
+    fn parse_ubuntu() {
+        let file = OsReleaseFile::parse(UBUNTU);
+        assert_eq!(file.get("ID"), Some("ubuntu"));
+        assert_eq!(file.id_like, vec!["debian".to_owned()]);
+        assert_eq!(file.get("NAME"), Some("Ubuntu"));
+        assert_eq!(file.get("VERSION_ID"), Some("18.04"));
+        assert_eq!(file.get("VERSION_CODENAME"), Some("bionic"));
+        assert_eq!(file.get("UBUNTU_CODENAME"), Some("bionic"));
+        assert_eq!(file.get("BUILD_ID"), None);
+        assert!(file.is_like("debian"));
+        assert!(!file.is_like("fedora"));
+        assert_eq!(file.support_end_date(), None);
+
+        let file = OsReleaseFile::parse("SUPPORT_END=2024-11-12\n");
+        assert_eq!(file.support_end_date(), Some(Date::new(2024, 11, 12)));
+        assert_eq!(OsReleaseFile::parse("SUPPORT_END=soon\n").support_end_date(), None);
+    }
+
+    #[test]
+    fn unquote_values() {
+        let pairs: &[(&str, Option<&str>)] = &[
+            ("plain",                  Some("plain")),
+            ("\"double quoted\"",      Some("double quoted")),
+            ("'single quoted'",        Some("single quoted")),
+            ("'it'\\''s'",             Some("it's")),
+            ("\"a \\\"b\\\" \\\\ \\$c\"", Some("a \"b\" \\ $c")),
+            ("\"keep \\n\"",           Some("keep \\n")),
+            ("escaped\\ space",        Some("escaped space")),
+            ("trailing # comment",     Some("trailing")),
+            ("\"unterminated",         None),
+            ("",                       Some("")),
+        ];
+        for &(value, expected) in pairs {
+            assert_eq!(unquote(value), expected.map(ToOwned::to_owned));
+        }
+    }
+
+    #[test]
+    fn skip_invalid_lines() {
+        let file = OsReleaseFile::parse("# comment\n\n1D=x\nnot a line\nID=arch\n");
+        assert_eq!(file.get("ID"), Some("arch"));
+        assert!(file.extra.is_empty());
+    }
+}
@@ -0,0 +1,264 @@
+//! Utilities specific to Red Hat Enterprise Linux (RHEL) and the
+//! distributions rebuilt from it, such as CentOS, Rocky Linux, AlmaLinux and
+//! Oracle Linux.
+
+use info::{KnownRelease, Lifecycle};
+use version::Version;
+
+releases! {
+    /// The release name of a known RHEL major version.
+    ///
+    /// Standard support is Maintenance Support, and the extended end is the
+    /// end of Extended Life-cycle Support (ELS).
+    pub enum OsRelease: "Red Hat Enterprise Linux" {
+        /// RHEL 5 (Tikanga).
+        Rhel5 {
+            version: (5, _),
+            name: "5",
+            codename: "tikanga",
+            lifecycle: [(2007, 03, 15), (2017, 03, 31), (2020, 11, 30)],
+            glibc: (2, 5, 0),
+        }
+
+        /// RHEL 6 (Santiago).
+        Rhel6 {
+            version: (6, _),
+            name: "6",
+            codename: "santiago",
+            lifecycle: [(2010, 11, 10), (2020, 11, 30), (2024, 06, 30)],
+            glibc: (2, 12, 0),
+        }
+
+        /// RHEL 7 (Maipo).
+        Rhel7 {
+            version: (7, _),
+            name: "7",
+            codename: "maipo",
+            lifecycle: [(2014, 06, 10), (2024, 06, 30), (2028, 06, 30)],
+            glibc: (2, 17, 0),
+        }
+
+        /// RHEL 8 (Ootpa).
+        Rhel8 {
+            version: (8, _),
+            name: "8",
+            codename: "ootpa",
+            lifecycle: [(2019, 05, 07), (2029, 05, 31), (2032, 05, 31)],
+            glibc: (2, 28, 0),
+        }
+
+        /// RHEL 9 (Plow).
+        Rhel9 {
+            version: (9, _),
+            name: "9",
+            codename: "plow",
+            lifecycle: [(2022, 05, 17), (2032, 05, 31), (2035, 05, 31)],
+            glibc: (2, 34, 0),
+        }
+
+        /// RHEL 10 (Coughlan).
+        Rhel10 {
+            version: (10, _),
+            name: "10",
+            codename: "coughlan",
+            lifecycle: [(2025, 05, 20), (2035, 05, 31), (2038, 05, 31)],
+            glibc: (2, 39, 0),
+        }
+    }
+
+    struct Data {
+        glibc: (u64, u64, u64),
+    }
+}
+
+impl OsRelease {
+    /// Returns the version of the GNU C Library that the release ships, which
+    /// its rebuilds share.
+    #[inline]
+    pub fn glibc_version(self) -> Version {
+        self.data().glibc.into()
+    }
+}
+
+/// RHEL or a distribution rebuilt from its sources.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Distro {
+    /// Red Hat Enterprise Linux itself.
+    Rhel,
+    /// CentOS Linux, which ended with CentOS Linux 8.
+    CentOs,
+    /// CentOS Stream, which tracks the next RHEL minor release.
+    CentOsStream,
+    /// Rocky Linux.
+    Rocky,
+    /// AlmaLinux OS.
+    Alma,
+    /// Oracle Linux.
+    Oracle,
+}
+
+impl Distro {
+    /// Returns the distribution with the lower-case `os-release` `ID`, such as
+    /// `rocky`.
+    ///
+    /// CentOS Linux and CentOS Stream share the `ID` of `centos`, for which
+    /// this returns `CentOs`.
+    pub fn from_id(id: &str) -> Option<Distro> {
+        match id {
+            "rhel"      => Some(Distro::Rhel),
+            "centos"    => Some(Distro::CentOs),
+            "rocky"     => Some(Distro::Rocky),
+            "almalinux" => Some(Distro::Alma),
+            "ol"        => Some(Distro::Oracle),
+            _ => None,
+        }
+    }
+
+    /// Returns the lifecycle of the distribution's build of `release`.
+    ///
+    /// Rebuilds other than CentOS follow the lifecycle of RHEL itself. CentOS
+    /// Linux and CentOS Stream end with the RHEL Maintenance Support of the
+    /// previous and the current release respectively, if not earlier.
+    #[allow(clippy::zero_prefixed_literal)]
+    pub fn lifecycle(self, release: OsRelease) -> Lifecycle {
+        use self::OsRelease::*;
+
+        let (released, support_end) = match (self, release) {
+            (Distro::CentOs, Rhel5)        => ((2007, 04, 12), Some((2017, 03, 31))),
+            (Distro::CentOs, Rhel6)        => ((2011, 07, 10), Some((2020, 11, 30))),
+            (Distro::CentOs, Rhel7)        => ((2014, 07, 07), Some((2024, 06, 30))),
+            (Distro::CentOs, Rhel8)        => ((2019, 09, 24), Some((2021, 12, 31))),
+            (Distro::CentOsStream, Rhel8)  => ((2019, 09, 24), Some((2024, 05, 31))),
+            (Distro::CentOsStream, Rhel9)  => ((2021, 12, 03), Some((2027, 05, 31))),
+            (Distro::CentOsStream, Rhel10) => ((2024, 12, 12), None),
+            _ => return release.lifecycle(),
+        };
+        Lifecycle {
+            released: released.into(),
+            support_end: support_end.map(Into::into),
+            extended_end: None,
+        }
+    }
+}
+
+impl From<Distro> for &'static str {
+    fn from(distro: Distro) -> Self {
+        match distro {
+            Distro::Rhel         => "Red Hat Enterprise Linux",
+            Distro::CentOs       => "CentOS Linux",
+            Distro::CentOsStream => "CentOS Stream",
+            Distro::Rocky        => "Rocky Linux",
+            Distro::Alma         => "AlmaLinux",
+            Distro::Oracle       => "Oracle Linux",
+        }
+    }
+}
+
+/// A Common Platform Enumeration (CPE) name, as found in
+/// `/etc/system-release-cpe`.
+///
+/// Both the URI binding, like `cpe:/o:redhat:enterprise_linux:9::baseos`, and
+/// the formatted string binding, like `cpe:2.3:o:amazon:amazon_linux:2023`,
+/// are accepted. Empty components are `None`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Cpe {
+    /// The vendor, such as `redhat`.
+    pub vendor: Option<String>,
+    /// The product, such as `enterprise_linux`.
+    pub product: Option<String>,
+    /// The version, such as `9`.
+    pub version: Option<String>,
+    /// The update, such as `9` for Oracle Linux 8.9.
+    pub update: Option<String>,
+}
+
+impl Cpe {
+    /// Parses a CPE name for an operating system, or returns `None` if `s` is
+    /// not one.
+    pub fn parse(s: &str) -> Option<Cpe> {
+        let mut parts = s.trim().split(':');
+        if parts.next() != Some("cpe") {
+            return None;
+        }
+        match parts.next() {
+            Some("2.3") if parts.next() == Some("o") => {},
+            Some("/o") => {},
+            _ => return None,
+        }
+
+        let mut part = || {
+            parts.next()
+                .filter(|part| !part.is_empty() && *part != "*" && *part != "-")
+                .map(ToOwned::to_owned)
+        };
+        Some(Cpe {
+            vendor: part(),
+            product: part(),
+            version: part(),
+            update: part(),
+        })
+    }
+
+    /// Returns the `os-release` `ID` of the Red Hat family distribution that
+    /// the name refers to, such as `rhel` or `fedora`.
+    pub fn os_release_id(&self) -> Option<&'static str> {
+        let id = match (self.vendor.as_deref()?, self.product.as_deref()?) {
+            ("redhat", "enterprise_linux") => "rhel",
+            ("centos", "centos")           => "centos",
+            ("rocky", "rocky")             => "rocky",
+            ("almalinux", "almalinux")     => "almalinux",
+            ("oracle", "linux")            => "ol",
+            ("fedoraproject", "fedora")    => "fedora",
+            ("amazon", "amazon_linux")     => "amzn",
+            _ => return None,
+        };
+        Some(id)
+    }
+
+    /// Returns the version and update as a version number, such as `8.9`.
+    pub fn version_number(&self) -> Option<Version> {
+        let version = self.version.as_ref()?;
+        let s = match &self.update {
+            Some(update) if !version.contains('.') => format!("{}.{}", version, update),
+            _ => version.clone(),
+        };
+        s.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type VersionTriple = (u64, u64, u64);
+
+    #[test]
+    fn parse_cpe() {
+        let pairs: &[(&str, Option<&str>, Option<VersionTriple>)] = &[
+            ("cpe:/o:redhat:enterprise_linux:9::baseos", Some("rhel"),   Some((9, 0, 0))),
+            ("cpe:/o:centos:centos:7",                   Some("centos"), Some((7, 0, 0))),
+            ("cpe:/o:oracle:linux:8:9:server",           Some("ol"),     Some((8, 9, 0))),
+            ("cpe:2.3:o:amazon:amazon_linux:2023",       Some("amzn"),   Some((2023, 0, 0))),
+            ("cpe:/o:fedoraproject:fedora:39\n",         Some("fedora"), Some((39, 0, 0))),
+            ("cpe:/o:example:example",                   None,           None),
+        ];
+        for &(s, id, version) in pairs {
+            let cpe = Cpe::parse(s).expect(s);
+            assert_eq!(cpe.os_release_id(), id, "{}", s);
+            assert_eq!(cpe.version_number(), version.map(Version::from), "{}", s);
+        }
+        assert_eq!(Cpe::parse("cpe:/a:redhat:openssl:3"), None);
+        assert_eq!(Cpe::parse("Fedora release 39"), None);
+    }
+
+    #[test]
+    fn lifecycle() {
+        let rhel8 = OsRelease::Rhel8;
+        assert_eq!(Distro::Rocky.lifecycle(rhel8), rhel8.lifecycle());
+        let centos8 = Distro::CentOs.lifecycle(rhel8);
+        assert!(centos8.support_end < rhel8.lifecycle().support_end);
+        assert_eq!(Distro::CentOsStream.lifecycle(OsRelease::Rhel10).support_end, None);
+        assert_eq!(rhel8.glibc_version(), Version::new(2, 28, 0));
+    }
+}
@@ -0,0 +1,136 @@
+//! Utilities specific to rolling release distributions, which have no
+//! numbered releases, such as Arch Linux.
+
+use date::Date;
+
+/// A rolling release distribution.
+///
+/// openSUSE Tumbleweed and the Debian testing and unstable suites are
+/// reported as [`OsMeta::Suse`] and [`OsMeta::Debian`] instead.
+///
+/// [`OsMeta::Suse`]: ../../../enum.OsMeta.html#variant.Suse
+/// [`OsMeta::Debian`]: ../../../enum.OsMeta.html#variant.Debian
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Distro {
+    /// Arch Linux.
+    Arch,
+    /// Manjaro Linux, which holds back Arch packages in its own branches.
+    Manjaro,
+    /// Gentoo Linux.
+    Gentoo,
+    /// Void Linux.
+    Void,
+}
+
+impl Distro {
+    /// Returns the distribution with the lower-case `os-release` `ID`, such as
+    /// `arch`.
+    pub fn from_id(id: &str) -> Option<Distro> {
+        match id {
+            "arch"                    => Some(Distro::Arch),
+            "manjaro" | "manjaro-arm" => Some(Distro::Manjaro),
+            "gentoo"                  => Some(Distro::Gentoo),
+            "void"                    => Some(Distro::Void),
+            _ => None,
+        }
+    }
+}
+
+impl From<Distro> for &'static str {
+    fn from(distro: Distro) -> Self {
+        match distro {
+            Distro::Arch    => "Arch Linux",
+            Distro::Manjaro => "Manjaro Linux",
+            Distro::Gentoo  => "Gentoo Linux",
+            Distro::Void    => "Void Linux",
+        }
+    }
+}
+
+/// The state of a host running a rolling release, in place of a version.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Snapshot {
+    /// The date of the snapshot that the system was installed or built from,
+    /// such as `20240101` in an `os-release` `BUILD_ID` or `IMAGE_VERSION`.
+    pub date: Option<Date>,
+    /// `BUILD_ID` from `os-release`, such as `rolling` on Arch Linux.
+    pub build_id: Option<String>,
+    /// The Gentoo profile that `/etc/portage/make.profile` links to, such as
+    /// `default/linux/amd64/23.0/systemd`.
+    pub profile: Option<String>,
+    /// The Manjaro branch that packages are taken from.
+    pub branch: Option<Branch>,
+}
+
+/// A branch of the Manjaro repositories, which each hold back packages from
+/// the Arch Linux repositories for a different length of time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Branch {
+    /// The default branch, updated every few weeks.
+    Stable,
+    /// Packages being tested for the stable branch.
+    Testing,
+    /// Packages as synchronized from Arch Linux.
+    Unstable,
+}
+
+impl Branch {
+    /// Returns the branch named `name` in `/etc/pacman-mirrors.conf`, such as
+    /// `testing` or `arm-testing`.
+    pub fn from_name(name: &str) -> Option<Branch> {
+        match name.trim().trim_start_matches("arm-") {
+            "stable"   => Some(Branch::Stable),
+            "testing"  => Some(Branch::Testing),
+            "unstable" => Some(Branch::Unstable),
+            _ => None,
+        }
+    }
+}
+
+impl From<Branch> for &'static str {
+    fn from(branch: Branch) -> Self {
+        match branch {
+            Branch::Stable   => "stable",
+            Branch::Testing  => "testing",
+            Branch::Unstable => "unstable",
+        }
+    }
+}
+
+/// Returns the snapshot date that an identifier such as `20240101` or
+/// `20240101.0.204074` starts with.
+pub fn snapshot_date(id: &str) -> Option<Date> {
+    let id = id.trim();
+    let rest = id.get(8..)?;
+    if rest.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    Date::parse_compact(&id[..8]).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_dates() {
+        let pairs = [
+            ("20240101",          Some(Date::new(2024, 1, 1))),
+            ("20240101.0.204074", Some(Date::new(2024, 1, 1))),
+            ("rolling",           None),
+            ("202401011",         None),
+            ("20241301",          None),
+        ];
+        for &(id, date) in &pairs {
+            assert_eq!(snapshot_date(id), date, "{}", id);
+        }
+    }
+
+    #[test]
+    fn branches() {
+        assert_eq!(Branch::from_name("testing"), Some(Branch::Testing));
+        assert_eq!(Branch::from_name("arm-unstable"), Some(Branch::Unstable));
+        assert_eq!(Branch::from_name("arm"), None);
+    }
+}
@@ -0,0 +1,382 @@
+//! Utilities specific to SUSE Linux Enterprise (SLE) and openSUSE.
+
+use std::fmt;
+use std::str::FromStr;
+
+use date::Date;
+use info::{KnownRelease, Lifecycle};
+use version::{ParseVersionError, Version};
+
+releases! {
+    /// The release name of a known SUSE Linux Enterprise version and service
+    /// pack, which openSUSE Leap is built from.
+    ///
+    /// The minor version is the service pack. Standard support is General
+    /// Support, and the extended end is the end of Long Term Service Pack
+    /// Support (LTSS). Releases are ordered by version, so service packs of
+    /// SLE 12 that came out after SLE 15 still come before it.
+    pub enum OsRelease: "SUSE Linux Enterprise" {
+        /// SLE 12.
+        Sle12 {
+            version: (12, 0),
+            name: "12",
+            codename: -,
+            lifecycle: [(2014, 10, 27), (2016, 06, 30), (2019, 07, 01)],
+            leap: None,
+        }
+
+        /// SLE 12 SP1 and openSUSE Leap 42.1.
+        Sle12Sp1 {
+            version: (12, 1),
+            name: "12 SP1",
+            codename: -,
+            lifecycle: [(2015, 12, 15), (2017, 05, 31), (2020, 05, 31)],
+            leap: Some((42, 1)),
+        }
+
+        /// SLE 12 SP2 and openSUSE Leap 42.2.
+        Sle12Sp2 {
+            version: (12, 2),
+            name: "12 SP2",
+            codename: -,
+            lifecycle: [(2016, 11, 08), (2018, 03, 31), (2021, 03, 31)],
+            leap: Some((42, 2)),
+        }
+
+        /// SLE 12 SP3 and openSUSE Leap 42.3.
+        Sle12Sp3 {
+            version: (12, 3),
+            name: "12 SP3",
+            codename: -,
+            lifecycle: [(2017, 09, 07), (2019, 06, 30), (2022, 06, 30)],
+            leap: Some((42, 3)),
+        }
+
+        /// SLE 12 SP4.
+        Sle12Sp4 {
+            version: (12, 4),
+            name: "12 SP4",
+            codename: -,
+            lifecycle: [(2018, 12, 12), (2020, 06, 30), (2023, 06, 30)],
+            leap: None,
+        }
+
+        /// SLE 12 SP5.
+        Sle12Sp5 {
+            version: (12, 5),
+            name: "12 SP5",
+            codename: -,
+            lifecycle: [(2019, 12, 09), (2024, 10, 31), (2027, 10, 31)],
+            leap: None,
+        }
+
+        /// SLE 15 and openSUSE Leap 15.0.
+        Sle15 {
+            version: (15, 0),
+            name: "15",
+            codename: -,
+            lifecycle: [(2018, 07, 16), (2019, 12, 31), (2022, 12, 31)],
+            leap: Some((15, 0)),
+        }
+
+        /// SLE 15 SP1 and openSUSE Leap 15.1.
+        Sle15Sp1 {
+            version: (15, 1),
+            name: "15 SP1",
+            codename: -,
+            lifecycle: [(2019, 06, 24), (2021, 01, 31), (2024, 01, 31)],
+            leap: Some((15, 1)),
+        }
+
+        /// SLE 15 SP2 and openSUSE Leap 15.2.
+        Sle15Sp2 {
+            version: (15, 2),
+            name: "15 SP2",
+            codename: -,
+            lifecycle: [(2020, 07, 21), (2021, 12, 31), (2024, 12, 31)],
+            leap: Some((15, 2)),
+        }
+
+        /// SLE 15 SP3 and openSUSE Leap 15.3.
+        Sle15Sp3 {
+            version: (15, 3),
+            name: "15 SP3",
+            codename: -,
+            lifecycle: [(2021, 06, 22), (2022, 12, 31), (2025, 12, 31)],
+            leap: Some((15, 3)),
+        }
+
+        /// SLE 15 SP4 and openSUSE Leap 15.4.
+        Sle15Sp4 {
+            version: (15, 4),
+            name: "15 SP4",
+            codename: -,
+            lifecycle: [(2022, 06, 21), (2023, 12, 31), (2026, 12, 31)],
+            leap: Some((15, 4)),
+        }
+
+        /// SLE 15 SP5 and openSUSE Leap 15.5.
+        Sle15Sp5 {
+            version: (15, 5),
+            name: "15 SP5",
+            codename: -,
+            lifecycle: [(2023, 06, 20), (2024, 12, 31), (2027, 12, 31)],
+            leap: Some((15, 5)),
+        }
+
+        /// SLE 15 SP6 and openSUSE Leap 15.6.
+        Sle15Sp6 {
+            version: (15, 6),
+            name: "15 SP6",
+            codename: -,
+            lifecycle: [(2024, 06, 25), (2025, 12, 31), (2028, 12, 31)],
+            leap: Some((15, 6)),
+        }
+
+        /// SLE 15 SP7.
+        Sle15Sp7 {
+            version: (15, 7),
+            name: "15 SP7",
+            codename: -,
+            lifecycle: [(2025, 06, 17), (2031, 07, 31), (2034, 07, 31)],
+            leap: None,
+        }
+
+        /// SLE 16.0 and openSUSE Leap 16.0.
+        Sle16 {
+            version: (16, 0),
+            name: "16.0",
+            codename: -,
+            lifecycle: [(2025, 11, 04), -, -],
+            leap: Some((16, 0)),
+        }
+    }
+
+    struct Data {
+        leap: Option<(u64, u64)>,
+    }
+}
+
+impl OsRelease {
+    /// Returns the release that openSUSE Leap `version`, such as `15.5`, is
+    /// built from.
+    pub fn from_leap_version<V: Into<Version>>(version: V) -> Option<Self> {
+        let version = version.into();
+        ALL.iter().cloned().find(|release| {
+            release.data().leap == Some((version.major, version.minor))
+        })
+    }
+
+    /// Returns the version of openSUSE Leap built from the release, if any.
+    #[inline]
+    pub fn leap_version(self) -> Option<Version> {
+        self.data().leap.map(Into::into)
+    }
+
+    /// Returns the service pack of the release, where `0` is the initial
+    /// release.
+    #[inline]
+    pub fn service_pack(self) -> u64 {
+        self.data().version.minor
+    }
+}
+
+/// A SUSE distribution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Distro {
+    /// SUSE Linux Enterprise Server.
+    Sles,
+    /// SUSE Linux Enterprise Desktop.
+    Sled,
+    /// openSUSE Leap, built from SUSE Linux Enterprise.
+    Leap,
+    /// openSUSE Tumbleweed, a rolling release.
+    Tumbleweed,
+}
+
+impl Distro {
+    /// Returns the distribution with the lower-case `os-release` `ID`, such as
+    /// `opensuse-leap`.
+    ///
+    /// openSUSE Leap 42.x reports the `ID` of `opensuse`, as did Tumbleweed at
+    /// the time, which can only be told apart by its snapshot `VERSION_ID`.
+    pub fn from_id(id: &str) -> Option<Distro> {
+        match id {
+            "sles"                => Some(Distro::Sles),
+            "sled"                => Some(Distro::Sled),
+            "opensuse-leap"       => Some(Distro::Leap),
+            "opensuse"            => Some(Distro::Leap),
+            "opensuse-tumbleweed" => Some(Distro::Tumbleweed),
+            _ => None,
+        }
+    }
+
+    /// Returns the lifecycle of the distribution's build of `release`.
+    ///
+    /// openSUSE Leap releases have their own release dates and are maintained
+    /// until shortly after the next one comes out, with no extended support.
+    #[allow(clippy::zero_prefixed_literal)]
+    pub fn lifecycle(self, release: OsRelease) -> Lifecycle {
+        use self::OsRelease::*;
+
+        let (released, support_end) = match (self, release) {
+            (Distro::Leap, Sle12Sp1) => ((2015, 11, 04), Some((2017, 05, 17))),
+            (Distro::Leap, Sle12Sp2) => ((2016, 11, 16), Some((2018, 01, 26))),
+            (Distro::Leap, Sle12Sp3) => ((2017, 07, 26), Some((2019, 07, 01))),
+            (Distro::Leap, Sle15)    => ((2018, 05, 25), Some((2019, 12, 03))),
+            (Distro::Leap, Sle15Sp1) => ((2019, 05, 22), Some((2021, 02, 02))),
+            (Distro::Leap, Sle15Sp2) => ((2020, 07, 02), Some((2022, 01, 04))),
+            (Distro::Leap, Sle15Sp3) => ((2021, 06, 02), Some((2022, 12, 31))),
+            (Distro::Leap, Sle15Sp4) => ((2022, 06, 08), Some((2023, 12, 07))),
+            (Distro::Leap, Sle15Sp5) => ((2023, 06, 07), Some((2024, 12, 31))),
+            (Distro::Leap, Sle15Sp6) => ((2024, 06, 12), Some((2026, 04, 30))),
+            (Distro::Leap, Sle16)    => ((2025, 10, 01), None),
+            _ => return release.lifecycle(),
+        };
+        Lifecycle {
+            released: released.into(),
+            support_end: support_end.map(Into::into),
+            extended_end: None,
+        }
+    }
+}
+
+impl From<Distro> for &'static str {
+    fn from(distro: Distro) -> Self {
+        match distro {
+            Distro::Sles       => "SUSE Linux Enterprise Server",
+            Distro::Sled       => "SUSE Linux Enterprise Desktop",
+            Distro::Leap       => "openSUSE Leap",
+            Distro::Tumbleweed => "openSUSE Tumbleweed",
+        }
+    }
+}
+
+/// The version of a SUSE distribution, which may name a service pack or a
+/// snapshot date rather than a `MAJOR.MINOR` version.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SuseVersion {
+    /// A numbered release, such as openSUSE Leap `15.5`.
+    Release(Version),
+    /// A SUSE Linux Enterprise release and its service pack, such as
+    /// `15-SP5`, where a service pack of `0` is the initial release.
+    ServicePack {
+        /// The major version, such as `15`.
+        major: u64,
+        /// The service pack, such as `5`.
+        service_pack: u64,
+    },
+    /// The date of a rolling release snapshot, such as openSUSE Tumbleweed
+    /// `20240101`.
+    Snapshot(Date),
+}
+
+impl SuseVersion {
+    /// Parses a version like `15.5`, `15-SP5` or `20240101`.
+    #[inline]
+    pub fn parse(version: &str) -> Result<SuseVersion, ParseVersionError> {
+        version.parse()
+    }
+
+    /// Returns the version number, such as `15.5.0` for `15-SP5`, or `None`
+    /// for a snapshot.
+    pub fn version(&self) -> Option<Version> {
+        match *self {
+            SuseVersion::Release(version) => Some(version),
+            SuseVersion::ServicePack { major, service_pack } => {
+                Some(Version::new(major, service_pack, 0))
+            },
+            SuseVersion::Snapshot(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for SuseVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SuseVersion::Release(version) => {
+                write!(f, "{}.{}", version.major, version.minor)
+            },
+            SuseVersion::ServicePack { major, service_pack: 0 } => {
+                write!(f, "{}", major)
+            },
+            SuseVersion::ServicePack { major, service_pack } => {
+                write!(f, "{}-SP{}", major, service_pack)
+            },
+            SuseVersion::Snapshot(date) => {
+                write!(f, "{:04}{:02}{:02}", date.year, date.month, date.day)
+            },
+        }
+    }
+}
+
+impl FromStr for SuseVersion {
+    type Err = ParseVersionError;
+
+    fn from_str(s: &str) -> Result<SuseVersion, ParseVersionError> {
+        let s = s.trim();
+        if let Ok(date) = Date::parse_compact(s) {
+            return Ok(SuseVersion::Snapshot(date));
+        }
+
+        let upper = s.to_uppercase();
+        let mut parts = upper.splitn(2, "SP");
+        let major = parts.next().unwrap_or_default();
+        match parts.next() {
+            Some(service_pack) => {
+                let major = major.trim_end_matches(&['-', ' '][..]);
+                Ok(SuseVersion::ServicePack {
+                    major: major.parse().map_err(ParseVersionError::MajorInt)?,
+                    service_pack: service_pack.parse()
+                        .map_err(ParseVersionError::MinorInt)?,
+                })
+            },
+            None => s.parse().map(SuseVersion::Release),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_version() {
+        let pairs = [
+            ("15.5",     SuseVersion::Release(Version::new(15, 5, 0))),
+            ("42.3",     SuseVersion::Release(Version::new(42, 3, 0))),
+            ("15-SP5",   SuseVersion::ServicePack { major: 15, service_pack: 5 }),
+            ("12 SP3",   SuseVersion::ServicePack { major: 12, service_pack: 3 }),
+            ("20240101", SuseVersion::Snapshot(Date::new(2024, 1, 1))),
+        ];
+        for (s, version) in &pairs {
+            assert_eq!(SuseVersion::parse(s).as_ref(), Ok(version), "{}", s);
+            assert_eq!(version.to_string(), s.replace(' ', "-"));
+        }
+        assert!(SuseVersion::parse("15-SPx").is_err());
+        assert!(SuseVersion::parse("").is_err());
+    }
+
+    #[test]
+    fn leap() {
+        assert_eq!(OsRelease::from_leap_version((15, 5)), Some(OsRelease::Sle15Sp5));
+        assert_eq!(OsRelease::from_leap_version((42, 3)), Some(OsRelease::Sle12Sp3));
+        assert_eq!(OsRelease::from_leap_version((15, 7)), None);
+        assert_eq!(OsRelease::Sle15Sp5.service_pack(), 5);
+
+        // SLE 12 and 15 service packs overlap, so releases are ordered by
+        // version but not by date
+        for pair in ALL.windows(2) {
+            assert!(pair[0].version() < pair[1].version());
+        }
+        for &release in ALL {
+            assert_eq!(OsRelease::parse(release.name()), Ok(release));
+            let lifecycle = Distro::Leap.lifecycle(release);
+            match release.leap_version() {
+                Some(_) => assert_ne!(lifecycle, release.lifecycle()),
+                None => assert_eq!(lifecycle, release.lifecycle()),
+            }
+        }
+    }
+}
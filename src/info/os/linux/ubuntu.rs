@@ -1,225 +1,685 @@
 //! Utilities specific to Ubuntu Linux.
 
-use version::Version;
-
-use self::OsRelease::*;
-
-/// The release name of a known Ubuntu version.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
-pub enum OsRelease {
-    /// Feisty Fawn (Ubuntu 7.04, Linux 2.6.20).
-    FeistyFawn,
-    /// Gutsy Gibbon (Ubuntu 7.10, Linux 2.6.22).
-    GutsyGibbon,
-    /// Hardy Heron (Ubuntu 8.04, Linux 2.6.24).
-    HardyHeron,
-    /// Intrepid Ibex (Ubuntu 8.10, Linux 2.6.27).
-    IntrepidIbex,
-    /// Jaunty Jackalope (Ubuntu 9.04, Linux 2.6.28).
-    JauntyJackalope,
-    /// Karmic Koala (Ubuntu 9.10, Linux 2.6.31).
-    KarmicKoala,
-    /// Lucid Lynx (Ubuntu 10.04, Linux 2.6.32).
-    LucidLynx,
-    /// Maverick Meerkat (Ubuntu 10.10, Linux 2.6.35).
-    MaverickMeerkat,
-    /// Natty Narwhal (Ubuntu 11.04, Linux 2.6.38).
-    NattyNarwhal,
-    /// Oneiric Ocelot (Ubuntu 11.10, Linux 3.0).
-    OneiricOcelot,
-    /// Precise Pangolin (Ubuntu 12.04, Linux 3.2+).
-    PrecisePangolin,
-    /// Quantal Quetzal (Ubuntu 12.10, Linux 3.5).
-    QuantalQuetzal,
-    /// Raring Ringtail (Ubuntu 13.04, Linux 3.8).
-    RaringRingtail,
-    /// Saucy Salamander (Ubuntu 13.10, Linux 3.11).
-    SaucySalamander,
-    /// Trusty Tahr (Ubuntu 14.04, Linux 3.13).
-    TrustyTahr,
-    /// Utopic Unicorn (Ubuntu 14.10, Linux 3.16).
-    UtopicUnicorn,
-    /// Vivid Vervet (Ubuntu 15.04, Linux 3.19).
-    VividVervet,
-    /// Wily Werewolf (Ubuntu 15.10, Linux 4.2).
-    WilyWerewolf,
-    /// Xenial Xerus (Ubuntu 16.04, Linux 4.4).
-    XenialXerus,
-    /// Yakkety Yak (Ubuntu 16.10, Linux 4.8).
-    YakketyYak,
-    /// Zesty Zapus (Ubuntu 17.04, Linux 4.10).
-    ZestyZapus,
-    /// Artful Aardvark (Ubuntu 17.10, Linux 4.13).
-    ArtfulAardvark,
-    /// Bionic Beaver (Ubuntu 18.04, Linux 4.15).
-    BionicBeaver,
-    /// Cosmic Cuttlefish (Ubuntu 18.10, Linux 4.18).
-    CosmicCuttlefish,
-    // We assume this value will never be used, so `unreachable_unchecked()` is
-    // fine to use
-    #[doc(hidden)]
-    _NonExhaustive,
-}
+use date::Date;
+use version::{KernelVersion, Version};
+
+releases! {
+    /// The release name of a known Ubuntu version.
+    ///
+    /// The extended end of a release's lifecycle is the end of Expanded
+    /// Security Maintenance (ESM), or of server support for LTS releases that
+    /// predate it, as listed by `distro-info`.
+    pub enum OsRelease: "Ubuntu" {
+        /// Feisty Fawn (Ubuntu 7.04, Linux 2.6.20).
+        FeistyFawn {
+            version: (07, 04),
+            name: "Feisty Fawn",
+            codename: "feisty",
+            lifecycle: [(2007, 04, 19), (2008, 10, 19), -],
+            min_kernel: (2, 06, 20),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Gutsy Gibbon (Ubuntu 7.10, Linux 2.6.22).
+        GutsyGibbon {
+            version: (07, 10),
+            name: "Gutsy Gibbon",
+            codename: "gutsy",
+            lifecycle: [(2007, 10, 18), (2009, 04, 18), -],
+            min_kernel: (2, 06, 22),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Hardy Heron (Ubuntu 8.04, Linux 2.6.24).
+        HardyHeron {
+            version: (08, 04),
+            name: "Hardy Heron",
+            codename: "hardy",
+            lifecycle: [(2008, 04, 24), (2011, 05, 12), (2013, 05, 09)],
+            min_kernel: (2, 06, 24),
+            lts: true,
+            point_releases: &[],
+        }
+
+        /// Intrepid Ibex (Ubuntu 8.10, Linux 2.6.27).
+        IntrepidIbex {
+            version: (08, 10),
+            name: "Intrepid Ibex",
+            codename: "intrepid",
+            lifecycle: [(2008, 10, 30), (2010, 04, 30), -],
+            min_kernel: (2, 06, 27),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Jaunty Jackalope (Ubuntu 9.04, Linux 2.6.28).
+        JauntyJackalope {
+            version: (09, 04),
+            name: "Jaunty Jackalope",
+            codename: "jaunty",
+            lifecycle: [(2009, 04, 23), (2010, 10, 23), -],
+            min_kernel: (2, 06, 28),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Karmic Koala (Ubuntu 9.10, Linux 2.6.31).
+        KarmicKoala {
+            version: (09, 10),
+            name: "Karmic Koala",
+            codename: "karmic",
+            lifecycle: [(2009, 10, 29), (2011, 04, 30), -],
+            min_kernel: (2, 06, 31),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Lucid Lynx (Ubuntu 10.04, Linux 2.6.32).
+        LucidLynx {
+            version: (10, 04),
+            name: "Lucid Lynx",
+            codename: "lucid",
+            lifecycle: [(2010, 04, 29), (2013, 05, 09), (2015, 04, 30)],
+            min_kernel: (2, 06, 32),
+            lts: true,
+            point_releases: &[],
+        }
+
+        /// Maverick Meerkat (Ubuntu 10.10, Linux 2.6.35).
+        MaverickMeerkat {
+            version: (10, 10),
+            name: "Maverick Meerkat",
+            codename: "maverick",
+            lifecycle: [(2010, 10, 10), (2012, 04, 10), -],
+            min_kernel: (2, 06, 35),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Natty Narwhal (Ubuntu 11.04, Linux 2.6.38).
+        NattyNarwhal {
+            version: (11, 04),
+            name: "Natty Narwhal",
+            codename: "natty",
+            lifecycle: [(2011, 04, 28), (2012, 10, 28), -],
+            min_kernel: (2, 06, 38),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Oneiric Ocelot (Ubuntu 11.10, Linux 3.0).
+        OneiricOcelot {
+            version: (11, 10),
+            name: "Oneiric Ocelot",
+            codename: "oneiric",
+            lifecycle: [(2011, 10, 13), (2013, 05, 09), -],
+            min_kernel: (3, 00, 00),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Precise Pangolin (Ubuntu 12.04, Linux 3.2+).
+        PrecisePangolin {
+            version: (12, 04),
+            name: "Precise Pangolin",
+            codename: "precise",
+            lifecycle: [(2012, 04, 26), (2017, 04, 28), (2019, 04, 26)],
+            min_kernel: (3, 02, 00),
+            lts: true,
+            point_releases: &[
+                (1, (2012, 08, 23), (3, 02)),
+                (2, (2013, 02, 14), (3, 05)),
+                (3, (2013, 08, 23), (3, 08)),
+                (4, (2014, 02, 06), (3, 11)),
+                (5, (2014, 08, 07), (3, 13)),
+            ],
+        }
+
+        /// Quantal Quetzal (Ubuntu 12.10, Linux 3.5).
+        QuantalQuetzal {
+            version: (12, 10),
+            name: "Quantal Quetzal",
+            codename: "quantal",
+            lifecycle: [(2012, 10, 18), (2014, 05, 16), -],
+            min_kernel: (3, 05, 00),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Raring Ringtail (Ubuntu 13.04, Linux 3.8).
+        RaringRingtail {
+            version: (13, 04),
+            name: "Raring Ringtail",
+            codename: "raring",
+            lifecycle: [(2013, 04, 25), (2014, 01, 27), -],
+            min_kernel: (3, 08, 00),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Saucy Salamander (Ubuntu 13.10, Linux 3.11).
+        SaucySalamander {
+            version: (13, 10),
+            name: "Saucy Salamander",
+            codename: "saucy",
+            lifecycle: [(2013, 10, 17), (2014, 07, 17), -],
+            min_kernel: (3, 11, 00),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Trusty Tahr (Ubuntu 14.04, Linux 3.13).
+        TrustyTahr {
+            version: (14, 04),
+            name: "Trusty Tahr",
+            codename: "trusty",
+            lifecycle: [(2014, 04, 17), (2019, 04, 25), (2024, 04, 25)],
+            min_kernel: (3, 13, 00),
+            lts: true,
+            point_releases: &[
+                (1, (2014, 07, 24), (3, 13)),
+                (2, (2015, 02, 20), (3, 16)),
+                (3, (2015, 08, 06), (3, 19)),
+                (4, (2016, 02, 18), (4, 02)),
+                (5, (2016, 08, 04), (4, 04)),
+                (6, (2019, 03, 07), (4, 04)),
+            ],
+        }
+
+        /// Utopic Unicorn (Ubuntu 14.10, Linux 3.16).
+        UtopicUnicorn {
+            version: (14, 10),
+            name: "Utopic Unicorn",
+            codename: "utopic",
+            lifecycle: [(2014, 10, 23), (2015, 07, 23), -],
+            min_kernel: (3, 16, 00),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Vivid Vervet (Ubuntu 15.04, Linux 3.19).
+        VividVervet {
+            version: (15, 04),
+            name: "Vivid Vervet",
+            codename: "vivid",
+            lifecycle: [(2015, 04, 23), (2016, 02, 04), -],
+            min_kernel: (3, 19, 00),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Wily Werewolf (Ubuntu 15.10, Linux 4.2).
+        WilyWerewolf {
+            version: (15, 10),
+            name: "Wily Werewolf",
+            codename: "wily",
+            lifecycle: [(2015, 10, 22), (2016, 07, 28), -],
+            min_kernel: (4, 02, 00),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Xenial Xerus (Ubuntu 16.04, Linux 4.4).
+        XenialXerus {
+            version: (16, 04),
+            name: "Xenial Xerus",
+            codename: "xenial",
+            lifecycle: [(2016, 04, 21), (2021, 04, 30), (2026, 04, 23)],
+            min_kernel: (4, 04, 00),
+            lts: true,
+            point_releases: &[
+                (1, (2016, 07, 21), (4, 04)),
+                (2, (2017, 02, 16), (4, 08)),
+                (3, (2017, 08, 03), (4, 10)),
+                (4, (2018, 03, 01), (4, 13)),
+                (5, (2018, 08, 02), (4, 15)),
+                (6, (2019, 02, 28), (4, 15)),
+                (7, (2020, 08, 13), (4, 15)),
+            ],
+        }
+
+        /// Yakkety Yak (Ubuntu 16.10, Linux 4.8).
+        YakketyYak {
+            version: (16, 10),
+            name: "Yakkety Yak",
+            codename: "yakkety",
+            lifecycle: [(2016, 10, 13), (2017, 07, 20), -],
+            min_kernel: (4, 08, 00),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Zesty Zapus (Ubuntu 17.04, Linux 4.10).
+        ZestyZapus {
+            version: (17, 04),
+            name: "Zesty Zapus",
+            codename: "zesty",
+            lifecycle: [(2017, 04, 13), (2018, 01, 13), -],
+            min_kernel: (4, 10, 00),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Artful Aardvark (Ubuntu 17.10, Linux 4.13).
+        ArtfulAardvark {
+            version: (17, 10),
+            name: "Artful Aardvark",
+            codename: "artful",
+            lifecycle: [(2017, 10, 19), (2018, 07, 19), -],
+            min_kernel: (4, 13, 00),
+            lts: false,
+            point_releases: &[],
+        }
 
-impl<'a> From<OsRelease> for &'a str {
-    fn from(release: OsRelease) -> Self {
-        match release {
-            FeistyFawn       => "Feisty Fawn",
-            GutsyGibbon      => "Gutsy Gibbon",
-            HardyHeron       => "Hardy Heron",
-            IntrepidIbex     => "Intrepid Ibex",
-            JauntyJackalope  => "Jaunty Jackalope",
-            KarmicKoala      => "Karmic Koala",
-            LucidLynx        => "Lucid Lynx",
-            MaverickMeerkat  => "Maverick Meerkat",
-            NattyNarwhal     => "Natty Narwhal",
-            OneiricOcelot    => "Oneiric Ocelot",
-            PrecisePangolin  => "Precise Pangolin",
-            QuantalQuetzal   => "Quantal Quetzal",
-            RaringRingtail   => "Raring Ringtail",
-            SaucySalamander  => "Saucy Salamander",
-            TrustyTahr       => "Trusty Tahr",
-            UtopicUnicorn    => "Utopic Unicorn",
-            VividVervet      => "Vivid Vervet",
-            WilyWerewolf     => "Wily Werewolf",
-            XenialXerus      => "Xenial Xerus",
-            YakketyYak       => "Yakkety Yak",
-            ZestyZapus       => "Zesty Zapus",
-            ArtfulAardvark   => "Artful Aardvark",
-            BionicBeaver     => "Bionic Beaver",
-            CosmicCuttlefish => "Cosmic Cuttlefish",
-            _NonExhaustive   => unsafe { std::hint::unreachable_unchecked() },
+        /// Bionic Beaver (Ubuntu 18.04, Linux 4.15).
+        BionicBeaver {
+            version: (18, 04),
+            name: "Bionic Beaver",
+            codename: "bionic",
+            lifecycle: [(2018, 04, 26), (2023, 05, 31), (2028, 04, 26)],
+            min_kernel: (4, 15, 00),
+            lts: true,
+            point_releases: &[
+                (1, (2018, 07, 26), (4, 15)),
+                (2, (2019, 02, 14), (4, 18)),
+                (3, (2019, 08, 08), (5, 00)),
+                (4, (2020, 02, 12), (5, 03)),
+                (5, (2020, 08, 13), (5, 04)),
+                (6, (2021, 09, 17), (5, 04)),
+            ],
+        }
+
+        /// Cosmic Cuttlefish (Ubuntu 18.10, Linux 4.18).
+        CosmicCuttlefish {
+            version: (18, 10),
+            name: "Cosmic Cuttlefish",
+            codename: "cosmic",
+            lifecycle: [(2018, 10, 18), (2019, 07, 18), -],
+            min_kernel: (4, 18, 00),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Disco Dingo (Ubuntu 19.04, Linux 5.0).
+        DiscoDingo {
+            version: (19, 04),
+            name: "Disco Dingo",
+            codename: "disco",
+            lifecycle: [(2019, 04, 18), (2020, 01, 23), -],
+            min_kernel: (5, 00, 00),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Eoan Ermine (Ubuntu 19.10, Linux 5.3).
+        EoanErmine {
+            version: (19, 10),
+            name: "Eoan Ermine",
+            codename: "eoan",
+            lifecycle: [(2019, 10, 17), (2020, 07, 17), -],
+            min_kernel: (5, 03, 00),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Focal Fossa (Ubuntu 20.04, Linux 5.4).
+        FocalFossa {
+            version: (20, 04),
+            name: "Focal Fossa",
+            codename: "focal",
+            lifecycle: [(2020, 04, 23), (2025, 05, 29), (2030, 04, 23)],
+            min_kernel: (5, 04, 00),
+            lts: true,
+            point_releases: &[
+                (1, (2020, 08, 06), (5, 04)),
+                (2, (2021, 02, 04), (5, 08)),
+                (3, (2021, 08, 26), (5, 11)),
+                (4, (2022, 02, 24), (5, 13)),
+                (5, (2022, 09, 01), (5, 15)),
+                (6, (2023, 03, 23), (5, 15)),
+            ],
+        }
+
+        /// Groovy Gorilla (Ubuntu 20.10, Linux 5.8).
+        GroovyGorilla {
+            version: (20, 10),
+            name: "Groovy Gorilla",
+            codename: "groovy",
+            lifecycle: [(2020, 10, 22), (2021, 07, 22), -],
+            min_kernel: (5, 08, 00),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Hirsute Hippo (Ubuntu 21.04, Linux 5.11).
+        HirsuteHippo {
+            version: (21, 04),
+            name: "Hirsute Hippo",
+            codename: "hirsute",
+            lifecycle: [(2021, 04, 22), (2022, 01, 20), -],
+            min_kernel: (5, 11, 00),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Impish Indri (Ubuntu 21.10, Linux 5.13).
+        ImpishIndri {
+            version: (21, 10),
+            name: "Impish Indri",
+            codename: "impish",
+            lifecycle: [(2021, 10, 14), (2022, 07, 14), -],
+            min_kernel: (5, 13, 00),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Jammy Jellyfish (Ubuntu 22.04, Linux 5.15).
+        JammyJellyfish {
+            version: (22, 04),
+            name: "Jammy Jellyfish",
+            codename: "jammy",
+            lifecycle: [(2022, 04, 21), (2027, 06, 01), (2032, 04, 21)],
+            min_kernel: (5, 15, 00),
+            lts: true,
+            point_releases: &[
+                (1, (2022, 08, 11), (5, 15)),
+                (2, (2023, 02, 23), (5, 19)),
+                (3, (2023, 08, 10), (6, 02)),
+                (4, (2024, 02, 22), (6, 05)),
+                (5, (2024, 09, 12), (6, 08)),
+            ],
+        }
+
+        /// Kinetic Kudu (Ubuntu 22.10, Linux 5.19).
+        KineticKudu {
+            version: (22, 10),
+            name: "Kinetic Kudu",
+            codename: "kinetic",
+            lifecycle: [(2022, 10, 20), (2023, 07, 20), -],
+            min_kernel: (5, 19, 00),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Lunar Lobster (Ubuntu 23.04, Linux 6.2).
+        LunarLobster {
+            version: (23, 04),
+            name: "Lunar Lobster",
+            codename: "lunar",
+            lifecycle: [(2023, 04, 20), (2024, 01, 25), -],
+            min_kernel: (6, 02, 00),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Mantic Minotaur (Ubuntu 23.10, Linux 6.5).
+        ManticMinotaur {
+            version: (23, 10),
+            name: "Mantic Minotaur",
+            codename: "mantic",
+            lifecycle: [(2023, 10, 12), (2024, 07, 11), -],
+            min_kernel: (6, 05, 00),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Noble Numbat (Ubuntu 24.04, Linux 6.8).
+        NobleNumbat {
+            version: (24, 04),
+            name: "Noble Numbat",
+            codename: "noble",
+            lifecycle: [(2024, 04, 25), (2029, 05, 31), (2034, 04, 25)],
+            min_kernel: (6, 08, 00),
+            lts: true,
+            point_releases: &[
+                (1, (2024, 08, 29), (6, 08)),
+                (2, (2025, 02, 20), (6, 11)),
+                (3, (2025, 08, 07), (6, 14)),
+            ],
+        }
+
+        /// Oracular Oriole (Ubuntu 24.10, Linux 6.11).
+        OracularOriole {
+            version: (24, 10),
+            name: "Oracular Oriole",
+            codename: "oracular",
+            lifecycle: [(2024, 10, 10), (2025, 07, 10), -],
+            min_kernel: (6, 11, 00),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Plucky Puffin (Ubuntu 25.04, Linux 6.14).
+        PluckyPuffin {
+            version: (25, 04),
+            name: "Plucky Puffin",
+            codename: "plucky",
+            lifecycle: [(2025, 04, 17), (2026, 01, 15), -],
+            min_kernel: (6, 14, 00),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Questing Quokka (Ubuntu 25.10, Linux 6.17).
+        QuestingQuokka {
+            version: (25, 10),
+            name: "Questing Quokka",
+            codename: "questing",
+            lifecycle: [(2025, 10, 09), (2026, 07, 09), -],
+            min_kernel: (6, 17, 00),
+            lts: false,
+            point_releases: &[],
+        }
+
+        /// Resolute Raccoon (Ubuntu 26.04, Linux 7.0).
+        ResoluteRaccoon {
+            version: (26, 04),
+            name: "Resolute Raccoon",
+            codename: "resolute",
+            lifecycle: [(2026, 04, 23), (2031, 05, 29), (2036, 04, 23)],
+            min_kernel: (7, 00, 00),
+            lts: true,
+            point_releases: &[],
         }
     }
-}
 
-impl OsRelease {
-    /// The minimum supported OS release.
-    pub const MIN: OsRelease = FeistyFawn;
-
-    /// The most recent OS release.
-    pub const LATEST: OsRelease = CosmicCuttlefish;
-
-    // This might be a large table; monomorphize here
-    fn _new(Version { major, minor, .. }: Version) -> Option<Self> {
-        match (major, minor) {
-            (07, 04) => Some(FeistyFawn),
-            (07, 10) => Some(GutsyGibbon),
-            (08, 04) => Some(HardyHeron),
-            (08, 10) => Some(IntrepidIbex),
-            (09, 04) => Some(JauntyJackalope),
-            (09, 10) => Some(KarmicKoala),
-            (10, 04) => Some(LucidLynx),
-            (10, 10) => Some(MaverickMeerkat),
-            (11, 04) => Some(NattyNarwhal),
-            (11, 10) => Some(OneiricOcelot),
-            (12, 04) => Some(PrecisePangolin),
-            (12, 10) => Some(QuantalQuetzal),
-            (13, 04) => Some(RaringRingtail),
-            (13, 10) => Some(SaucySalamander),
-            (14, 04) => Some(TrustyTahr),
-            (14, 10) => Some(UtopicUnicorn),
-            (15, 04) => Some(VividVervet),
-            (15, 10) => Some(WilyWerewolf),
-            (16, 04) => Some(XenialXerus),
-            (16, 10) => Some(YakketyYak),
-            (17, 04) => Some(ZestyZapus),
-            (17, 10) => Some(ArtfulAardvark),
-            (18, 04) => Some(BionicBeaver),
-            (18, 10) => Some(CosmicCuttlefish),
-            _ => None,
-        }
-    }
-
-    /// Returns the corresponding release for the Ubuntu version number.
-    pub fn new<V: Into<Version>>(version: V) -> Option<Self> {
-        Self::_new(version.into())
+    struct Data {
+        min_kernel: (u64, u64, u64),
+        lts: bool,
+        // (point, released, kernel series)
+        point_releases: &'static [(u64, (u16, u8, u8), (u64, u64))],
     }
+}
 
+impl OsRelease {
     /// Returns the minimum kernel version for the release.
+    #[inline]
     pub fn min_kernel_version(self) -> Version {
-        match self {
-            FeistyFawn       => (2, 06, 20),
-            GutsyGibbon      => (2, 06, 22),
-            HardyHeron       => (2, 06, 24),
-            IntrepidIbex     => (2, 06, 27),
-            JauntyJackalope  => (2, 06, 28),
-            KarmicKoala      => (2, 06, 31),
-            LucidLynx        => (2, 06, 32),
-            MaverickMeerkat  => (2, 06, 35),
-            NattyNarwhal     => (2, 06, 38),
-            OneiricOcelot    => (3, 00, 00),
-            PrecisePangolin  => (3, 02, 00),
-            QuantalQuetzal   => (3, 05, 00),
-            RaringRingtail   => (3, 08, 00),
-            SaucySalamander  => (3, 11, 00),
-            TrustyTahr       => (3, 13, 00),
-            UtopicUnicorn    => (3, 16, 00),
-            VividVervet      => (3, 19, 00),
-            WilyWerewolf     => (4, 02, 00),
-            XenialXerus      => (4, 04, 00),
-            YakketyYak       => (4, 08, 00),
-            ZestyZapus       => (4, 10, 00),
-            ArtfulAardvark   => (4, 13, 00),
-            BionicBeaver     => (4, 15, 00),
-            CosmicCuttlefish => (4, 18, 00),
-            _NonExhaustive   => unsafe { std::hint::unreachable_unchecked() },
-        }.into()
+        self.data().min_kernel.into()
     }
 
     /// Returns whether `self` is a Long Term Support (LTS) release.
     #[inline]
     pub fn is_lts(self) -> bool {
-        match self {
-            HardyHeron      |
-            LucidLynx       |
-            PrecisePangolin |
-            TrustyTahr      |
-            XenialXerus     |
-            BionicBeaver => true,
-            _ => false,
+        self.data().lts
+    }
+
+    /// Returns the point releases of the release, from oldest to newest.
+    ///
+    /// These are known for LTS releases as of Precise Pangolin (12.04).
+    pub fn point_releases(self) -> impl Iterator<Item = PointRelease> {
+        self.data().point_releases.iter().map(move |&(point, released, kernel)| {
+            PointRelease {
+                release: self,
+                point,
+                released: released.into(),
+                kernel: kernel.into(),
+            }
+        })
+    }
+
+    /// Returns the point release numbered `point`, such as `6` for 18.04.6.
+    pub fn point_release(self, point: u64) -> Option<PointRelease> {
+        self.point_releases().find(|release| release.point == point)
+    }
+
+    /// Returns the kernel series of the General Availability (GA) stack that
+    /// the release shipped with, such as `4.15` for Bionic Beaver.
+    pub fn ga_kernel(self) -> Version {
+        let Version { major, minor, .. } = self.min_kernel_version();
+        (major, minor).into()
+    }
+
+    /// Returns the kernel series of the Hardware Enablement (HWE) stacks that
+    /// were backported to the release by its point releases, from oldest to
+    /// newest.
+    pub fn hwe_kernels(self) -> Vec<Version> {
+        let ga_kernel = self.ga_kernel();
+        let mut kernels: Vec<Version> = self.point_releases()
+            .map(|release| release.kernel)
+            .filter(|&kernel| kernel != ga_kernel)
+            .collect();
+        kernels.dedup();
+        kernels
+    }
+
+    /// Returns the kernel stack that `kernel`, such as the output of
+    /// `uname -r`, belongs to on this release.
+    ///
+    /// Returns `None` if the kernel is from neither stack, such as a mainline
+    /// kernel.
+    pub fn kernel_stack(self, kernel: &KernelVersion) -> Option<KernelStack> {
+        let series = Version::from((kernel.version.major, kernel.version.minor));
+        if series == self.ga_kernel() {
+            Some(KernelStack::Ga)
+        } else if self.hwe_kernels().contains(&series) {
+            Some(KernelStack::Hwe(series))
+        } else {
+            None
         }
     }
 }
 
-impl From<OsRelease> for Version {
-    fn from(release: OsRelease) -> Version {
-        match release {
-            FeistyFawn       => (07, 04),
-            GutsyGibbon      => (07, 10),
-            HardyHeron       => (08, 04),
-            IntrepidIbex     => (08, 10),
-            JauntyJackalope  => (09, 04),
-            KarmicKoala      => (09, 10),
-            LucidLynx        => (10, 04),
-            MaverickMeerkat  => (10, 10),
-            NattyNarwhal     => (11, 04),
-            OneiricOcelot    => (11, 10),
-            PrecisePangolin  => (12, 04),
-            QuantalQuetzal   => (12, 10),
-            RaringRingtail   => (13, 04),
-            SaucySalamander  => (13, 10),
-            TrustyTahr       => (14, 04),
-            UtopicUnicorn    => (14, 10),
-            VividVervet      => (15, 04),
-            WilyWerewolf     => (15, 10),
-            XenialXerus      => (16, 04),
-            YakketyYak       => (16, 10),
-            ZestyZapus       => (17, 04),
-            ArtfulAardvark   => (17, 10),
-            BionicBeaver     => (18, 04),
-            CosmicCuttlefish => (18, 10),
-            _NonExhaustive   => unsafe { std::hint::unreachable_unchecked() },
-        }.into()
+/// A point release of an Ubuntu LTS release, such as 18.04.6.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PointRelease {
+    /// The release that this is a point release of.
+    pub release: OsRelease,
+    /// The point release number, such as `6` for 18.04.6.
+    pub point: u64,
+    /// When the point release came out.
+    pub released: Date,
+    /// The kernel series that the point release installs, such as `5.4` for
+    /// 18.04.5.
+    pub kernel: Version,
+}
+
+impl PointRelease {
+    /// Returns the point release for a version number such as `18.04.6`, if
+    /// known.
+    pub fn new<V: Into<Version>>(version: V) -> Option<PointRelease> {
+        let version = version.into();
+        OsRelease::new(version)?.point_release(version.patch)
     }
+
+    /// Returns the version number of the point release, such as `18.04.6`.
+    #[inline]
+    pub fn version(self) -> Version {
+        Version { patch: self.point, ..self.release.into() }
+    }
+
+    /// Returns the kernel stack that the point release installs.
+    pub fn kernel_stack(self) -> KernelStack {
+        if self.kernel == self.release.ga_kernel() {
+            KernelStack::Ga
+        } else {
+            KernelStack::Hwe(self.kernel)
+        }
+    }
+}
+
+/// A kernel stack supported on an Ubuntu LTS release.
+///
+/// Kernel modules must be built for each stack separately.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum KernelStack {
+    /// The General Availability (GA) kernel that the release shipped with.
+    Ga,
+    /// A Hardware Enablement (HWE) kernel from a later release, with its
+    /// series such as `5.4`.
+    Hwe(Version),
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use super::OsRelease::*;
+    use info::{KnownRelease, SupportStatus};
 
     #[test]
     fn cmp() {
         assert!(OsRelease::LATEST > OsRelease::MIN);
     }
+
+    #[test]
+    fn cmp_kernel() {
+        let kernel = KernelVersion::parse("4.15.0-45-generic").unwrap();
+        assert!(kernel >= BionicBeaver.min_kernel_version());
+        assert!(kernel < CosmicCuttlefish.min_kernel_version());
+    }
+
+    #[test]
+    fn point_releases() {
+        let point = PointRelease::new((18, 4, 6)).unwrap();
+        assert_eq!(point.release, BionicBeaver);
+        assert_eq!(point.version(), Version::new(18, 4, 6));
+        assert_eq!(point.kernel_stack(), KernelStack::Hwe((5, 4).into()));
+        assert_eq!(PointRelease::new((18, 4, 1)).unwrap().kernel_stack(), KernelStack::Ga);
+        assert_eq!(PointRelease::new((18, 4, 7)), None);
+        assert_eq!(PointRelease::new((18, 10, 1)), None);
+
+        for release in OsRelease::iter().filter(|release| release.is_lts()) {
+            for pair in release.point_releases().collect::<Vec<_>>().windows(2) {
+                assert_eq!(pair[0].point + 1, pair[1].point, "{:?}", pair[0]);
+                assert!(pair[0].released < pair[1].released, "{:?}", pair[0]);
+                assert!(pair[0].kernel <= pair[1].kernel, "{:?}", pair[0]);
+            }
+            if let Some(first) = release.point_releases().next() {
+                assert!(first.released > release.lifecycle().released);
+                assert_eq!(first.kernel_stack(), KernelStack::Ga, "{:?}", release);
+            }
+        }
+    }
+
+    #[test]
+    fn kernel_stack() {
+        let hwe = [(4, 18), (5, 0), (5, 3), (5, 4)];
+        let hwe: Vec<Version> = hwe.iter().map(|&series| series.into()).collect();
+        assert_eq!(BionicBeaver.hwe_kernels(), hwe);
+        assert!(CosmicCuttlefish.hwe_kernels().is_empty());
+
+        let pairs = [
+            ("4.15.0-213-generic",  Some(KernelStack::Ga)),
+            ("5.4.0-150-generic",   Some(KernelStack::Hwe((5, 4).into()))),
+            ("5.0.0-65-lowlatency", Some(KernelStack::Hwe((5, 0).into()))),
+            ("6.8.0-45-generic",    None),
+        ];
+        for &(kernel, stack) in &pairs {
+            let kernel = KernelVersion::parse(kernel).unwrap();
+            assert_eq!(BionicBeaver.kernel_stack(&kernel), stack, "{}", kernel);
+        }
+    }
+
+    #[test]
+    fn support_status() {
+        let on = Date::new(2024, 1, 1);
+        assert_eq!(BionicBeaver.support_status(on), SupportStatus::Extended);
+        assert_eq!(CosmicCuttlefish.support_status(on), SupportStatus::EndOfLife);
+
+        let on = Date::new(2018, 6, 1);
+        assert_eq!(BionicBeaver.support_status(on), SupportStatus::Supported);
+        assert_eq!(CosmicCuttlefish.support_status(on), SupportStatus::Unreleased);
+    }
 }
@@ -1,108 +1,118 @@
-use std::mem;
+//! Utilities specific to Apple macOS.
 
-use info::{OsInfo, OsMeta};
-use version::{Version, OsVersion};
-use self::OsRelease::*;
-
-const MIN_MINOR_VERSION: u64 = 7; // Lion
-const MAX_MINOR_VERSION: u64 = MIN_MINOR_VERSION + OsRelease::LATEST as u64;
+#[cfg(target_os = "macos")]
+use info::{OsInfo, OsMeta, Release};
+#[cfg(target_os = "macos")]
+use version::{KernelVersion, OsVersion};
 
+#[cfg(target_os = "macos")]
 pub(crate) fn get_info() -> OsInfo {
     let version = OsVersion::get();
-    let release = version.and_then(OsRelease::new);
+    let release = version.and_then(|version| {
+        Release::from_parts(OsRelease::new(version), Some(version.into()), None)
+    });
     OsInfo {
         meta: OsMeta::MacOs {
             release,
         },
         version,
+        kernel: KernelVersion::get(),
+        immutable: None,
+        guest: None,
     }
 }
 
-/// The release name of a known macOS version.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
-pub enum OsRelease {
-    /// macOS 10.7.
-    ///
-    /// This is the minimum version supported by Rust, according to
-    /// [tier 1 platforms](https://forge.rust-lang.org/platform-support.html#tier-1).
-    Lion,
-    /// macOS 10.8.
-    MountainLion,
-    /// macOS 10.9.
-    Mavericks,
-    /// macOS 10.10.
+releases! {
+    /// The release name of a known macOS version.
     ///
-    /// This is the minimum version that supports querying the version with
-    /// [`operatingSystemVersion`](https://developer.apple.com/documentation/foundation/nsprocessinfo/1410906-operatingsystemversion?language=objc).
-    Yosemite,
-    /// macOS 10.11.
-    ElCapitan,
-    /// macOS 10.12.
-    Sierra,
-    /// macOS 10.13.
-    HighSierra,
-    /// macOS 10.14.
-    Mojave,
-    // We assume this value will never be used, so `unreachable_unchecked()` is
-    // fine to use
-    #[doc(hidden)]
-    _NonExhaustive,
-}
+    /// Codenames are lower-case with underscores for spaces, as used by
+    /// Homebrew, such as `high_sierra`. Standard support ends when the next
+    /// release comes out, and the extended end is the last security update.
+    pub enum OsRelease: "macOS" {
+        /// macOS 10.7.
+        ///
+        /// This is the minimum version supported by Rust, according to
+        /// [tier 1 platforms](https://forge.rust-lang.org/platform-support.html#tier-1).
+        Lion {
+            version: (10, 7),
+            name: "Lion",
+            codename: "lion",
+            lifecycle: [(2011, 07, 20), (2012, 07, 25), (2013, 09, 12)],
+        }
 
-impl<'a> From<OsRelease> for &'a str {
-    fn from(release: OsRelease) -> Self {
-        match release {
-            Lion           => "Lion",
-            MountainLion   => "Mountain Lion",
-            Mavericks      => "Mavericks",
-            Yosemite       => "Yosemite",
-            ElCapitan      => "El Capitan",
-            Sierra         => "Sierra",
-            HighSierra     => "High Sierra",
-            Mojave         => "Mojave",
-            _NonExhaustive => unsafe { std::hint::unreachable_unchecked() },
+        /// macOS 10.8.
+        MountainLion {
+            version: (10, 8),
+            name: "Mountain Lion",
+            codename: "mountain_lion",
+            lifecycle: [(2012, 07, 25), (2013, 10, 22), (2015, 08, 13)],
+        }
+
+        /// macOS 10.9.
+        Mavericks {
+            version: (10, 9),
+            name: "Mavericks",
+            codename: "mavericks",
+            lifecycle: [(2013, 10, 22), (2014, 10, 16), (2016, 12, 13)],
         }
-    }
-}
 
-impl OsRelease {
-    /// The minimum supported OS release.
-    pub const MIN: OsRelease = Lion;
+        /// macOS 10.10.
+        ///
+        /// This is the minimum version that supports querying the version with
+        /// [`operatingSystemVersion`](https://developer.apple.com/documentation/foundation/nsprocessinfo/1410906-operatingsystemversion?language=objc).
+        Yosemite {
+            version: (10, 10),
+            name: "Yosemite",
+            codename: "yosemite",
+            lifecycle: [(2014, 10, 16), (2015, 09, 30), (2017, 07, 19)],
+        }
 
-    /// The most recent OS release.
-    pub const LATEST: OsRelease = Mojave;
+        /// macOS 10.11.
+        ElCapitan {
+            version: (10, 11),
+            name: "El Capitan",
+            codename: "el_capitan",
+            lifecycle: [(2015, 09, 30), (2016, 09, 20), (2018, 07, 09)],
+        }
 
-    /// Returns the corresponding release for the macOS version number.
-    pub fn new<V: Into<Version>>(version: V) -> Option<Self> {
-        let Version { major, minor, .. } = version.into();
-        match (major, minor) {
-            (10, MIN_MINOR_VERSION..=MAX_MINOR_VERSION) => unsafe {
-                Some(mem::transmute((minor - MIN_MINOR_VERSION) as u8))
-            },
-            _ => None,
+        /// macOS 10.12.
+        Sierra {
+            version: (10, 12),
+            name: "Sierra",
+            codename: "sierra",
+            lifecycle: [(2016, 09, 20), (2017, 09, 25), (2019, 09, 26)],
         }
-    }
-}
 
-impl From<OsRelease> for Version {
-    fn from(release: OsRelease) -> Version {
-        (10, MIN_MINOR_VERSION + release as u64).into()
+        /// macOS 10.13.
+        HighSierra {
+            version: (10, 13),
+            name: "High Sierra",
+            codename: "high_sierra",
+            lifecycle: [(2017, 09, 25), (2018, 09, 24), (2020, 11, 12)],
+        }
+
+        /// macOS 10.14.
+        Mojave {
+            version: (10, 14),
+            name: "Mojave",
+            codename: "mojave",
+            lifecycle: [(2018, 09, 24), (2019, 10, 07), (2021, 07, 21)],
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use version::Version;
 
     #[test]
     fn version_conversion() {
-        let minor_end = MAX_MINOR_VERSION + 1;
-        let major = 10;
-
-        assert_eq!(OsRelease::new((major, minor_end)), None);
+        assert_eq!(OsRelease::new((10, 6)), None);
+        assert_eq!(OsRelease::new((10, 15)), None);
 
-        for minor in MIN_MINOR_VERSION..minor_end {
-            let vers = Version::from((major, minor));
+        for minor in 7..15 {
+            let vers = Version::from((10, minor));
             let name = OsRelease::new(vers).unwrap();
             assert_eq!(Version::from(name), vers);
         }
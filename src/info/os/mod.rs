@@ -1,16 +1,35 @@
-//! Types for this specific operating system.
+//! Types for each supported operating system.
+//!
+//! Release information for every operating system is available on all
+//! platforms, so that artifacts targeting other systems can be reasoned about.
+//! Only querying the host is specific to the current platform.
 
+pub mod android;
+pub mod linux;
+pub mod macos;
+pub mod windows;
+
+// Types for the host are also available directly within this module
 cfg_if! {
     if #[cfg(target_os = "macos")] {
-        mod macos;
         pub use self::macos::*;
-    } else if #[cfg(target_os = "linux")] {
-        mod linux;
+    } else if #[cfg(any(target_os = "linux", target_os = "android"))] {
         pub use self::linux::*;
     } else if #[cfg(target_os = "windows")] {
-        mod windows;
         pub use self::windows::*;
     } else {
-        compile_error!("Unsupported platform");
+        use std::env;
+        use info::{OsInfo, OsMeta};
+        use version::{KernelVersion, OsVersion};
+
+        pub(crate) fn get_info() -> OsInfo {
+            OsInfo {
+                meta: OsMeta::Unknown { name: env::consts::OS },
+                version: OsVersion::get(),
+                kernel: KernelVersion::get(),
+                immutable: None,
+                guest: None,
+            }
+        }
     }
 }
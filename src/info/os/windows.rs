@@ -1,84 +1,90 @@
-use info::{OsInfo, OsMeta};
-use version::{Version, OsVersion};
-use self::OsRelease::*;
+//! Utilities specific to Microsoft Windows.
 
+#[cfg(target_os = "windows")]
+use info::{OsInfo, OsMeta, Release};
+#[cfg(target_os = "windows")]
+use version::{KernelVersion, OsVersion};
+
+#[cfg(target_os = "windows")]
 pub(crate) fn get_info() -> OsInfo {
     let version = OsVersion::get();
-    let release = version.and_then(OsRelease::new);
+    let release = version.and_then(|version| {
+        Release::from_parts(OsRelease::new(version), Some(version.into()), None)
+    });
     OsInfo {
-        meta: OsMeta::MacOs {
+        meta: OsMeta::Windows {
             release,
         },
         version,
+        kernel: KernelVersion::get(),
+        immutable: None,
+        guest: None,
     }
 }
 
-/// The release name of a known Windows version.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
-pub enum OsRelease {
-    /// Windows 7.
+releases! {
+    /// The release name of a known Windows version.
     ///
-    /// This is the minimum version supported by Rust, according to
-    /// [tier 1 platforms](https://forge.rust-lang.org/platform-support.html#tier-1).
-    Windows7,
-    /// Windows 8.
-    Windows8,
-    /// Windows 8.1.
-    Windows8_1,
-    /// Windows 10.
-    Windows10,
-    // We assume this value will never be used, so `unreachable_unchecked()` is
-    // fine to use
-    #[doc(hidden)]
-    _NonExhaustive,
-}
-
-impl<'a> From<OsRelease> for &'a str {
-    fn from(release: OsRelease) -> Self {
-        match release {
-            OsRelease::Windows7       => "7",
-            OsRelease::Windows8       => "8",
-            OsRelease::Windows8_1     => "8.1",
-            OsRelease::Windows10      => "10",
-            OsRelease::_NonExhaustive => unsafe {
-                std::hint::unreachable_unchecked()
-            },
+    /// Windows releases are referred to by number and have no codename.
+    /// Standard support is mainstream support, and the extended end is the
+    /// end of extended support.
+    pub enum OsRelease: "Windows" {
+        /// Windows 7.
+        ///
+        /// This is the minimum version supported by Rust, according to
+        /// [tier 1 platforms](https://forge.rust-lang.org/platform-support.html#tier-1).
+        Windows7 {
+            version: (06, 1),
+            name: "7",
+            codename: -,
+            lifecycle: [(2009, 10, 22), (2015, 01, 13), (2020, 01, 14)],
         }
-    }
-}
 
-impl OsRelease {
-    /// The minimum supported OS release.
-    pub const MIN: OsRelease = OsRelease::Windows7;
+        /// Windows 8.
+        Windows8 {
+            version: (06, 2),
+            name: "8",
+            codename: -,
+            lifecycle: [(2012, 10, 26), (2016, 01, 12), (2016, 01, 12)],
+        }
 
-    /// The most recent OS release.
-    pub const LATEST: OsRelease = OsRelease::Windows10;
+        /// Windows 8.1.
+        Windows8_1 {
+            version: (06, 3),
+            name: "8.1",
+            codename: -,
+            lifecycle: [(2013, 10, 17), (2018, 01, 09), (2023, 01, 10)],
+        }
 
-    /// Returns the corresponding release for the Windows version number.
-    pub fn new<V: Into<Version>>(version: V) -> Option<Self> {
-        let Version { major, minor, .. } = version.into();
-        match (major, minor) {
-            (06, 1) => Some(OsRelease::Windows7),
-            (06, 2) => Some(OsRelease::Windows8),
-            (06, 3) => Some(OsRelease::Windows8_1),
-            (10, 0) => Some(OsRelease::Windows10),
-            _ => None
+        /// Windows 10.
+        Windows10 {
+            version: (10, 0),
+            name: "10",
+            codename: -,
+            lifecycle: [(2015, 07, 29), (2020, 10, 13), (2025, 10, 14)],
         }
     }
 }
 
-impl From<OsRelease> for Version {
-    fn from(release: OsRelease) -> Version {
-        let pair = match release {
-            OsRelease::Windows7       => (06, 1),
-            OsRelease::Windows8       => (06, 2),
-            OsRelease::Windows8_1     => (06, 3),
-            OsRelease::Windows10      => (10, 0),
-            OsRelease::_NonExhaustive => unsafe {
-                std::hint::unreachable_unchecked()
+impl OsRelease {
+    /// Returns the client release with the build number `build`, such as
+    /// Windows 10 for 19045.
+    ///
+    /// Only builds that shipped to the general public are recognized. Windows
+    /// Server builds, such as 20348 for Windows Server 2022, and Insider
+    /// builds return `None`, as do Windows 11 builds as of 22000, which is not
+    /// yet known to this crate.
+    pub fn from_build(build: u64) -> Option<OsRelease> {
+        match build {
+            7600 | 7601 => Some(OsRelease::Windows7),
+            9200        => Some(OsRelease::Windows8),
+            9600        => Some(OsRelease::Windows8_1),
+            10240 | 10586 | 14393 | 15063 | 16299 | 17134 | 17763 |
+            18362 | 18363 | 19041 | 19042 | 19043 | 19044 | 19045 => {
+                Some(OsRelease::Windows10)
             },
-        };
-        pair.into()
+            _ => None,
+        }
     }
 }
 
@@ -90,4 +96,21 @@ mod tests {
     fn cmp() {
         assert!(OsRelease::LATEST > OsRelease::MIN);
     }
+
+    #[test]
+    fn builds() {
+        let pairs = [
+            (7601,  Some(OsRelease::Windows7)),
+            (10240, Some(OsRelease::Windows10)),
+            (10241, None),
+            (19041, Some(OsRelease::Windows10)),
+            (19045, Some(OsRelease::Windows10)),
+            (20348, None),
+            (22000, None),
+            (22631, None),
+        ];
+        for &(build, release) in &pairs {
+            assert_eq!(OsRelease::from_build(build), release, "{}", build);
+        }
+    }
 }
@@ -0,0 +1,460 @@
+//! Operating system releases, whether or not they are known to this crate.
+
+use std::error::Error;
+use std::fmt::{self, Debug};
+use std::hash::Hash;
+use std::iter::Copied;
+use std::ops::RangeBounds;
+use std::slice;
+
+use date::Date;
+use info::{Lifecycle, SupportStatus};
+use version::Version;
+
+/// An iterator over known releases, ordered by version.
+pub type ReleaseIter<R> = Copied<slice::Iter<'static, R>>;
+
+/// A release of some operating system that is known to this crate, such as
+/// [`ubuntu::OsRelease`](../os/linux/ubuntu/enum.OsRelease.html).
+///
+/// This allows for writing code that is generic over the releases of any
+/// operating system. Releases are ordered by version, which is also the order
+/// they came out in for every operating system except SUSE Linux Enterprise,
+/// where service packs of an older version can follow a newer version.
+pub trait KnownRelease:
+    Copy + Debug + Eq + Ord + Hash + Into<Version> + Into<&'static str> + 'static
+{
+    /// The name of the operating system, such as `Ubuntu`.
+    const OS_NAME: &'static str;
+
+    /// The known release with the lowest version.
+    const MIN: Self;
+
+    /// The known release with the highest version.
+    const LATEST: Self;
+
+    /// Returns every known release, ordered by version.
+    fn all() -> &'static [Self];
+
+    /// Returns the release for the version number, if known.
+    fn from_version(version: Version) -> Option<Self>;
+
+    /// Returns the lower-case codename of the release as used by package
+    /// managers and `os-release`, such as `bionic`, if the operating system
+    /// uses codenames.
+    fn codename(self) -> Option<&'static str>;
+
+    /// Returns the release date and support windows of the release.
+    fn lifecycle(self) -> Lifecycle;
+
+    /// Returns the release for the codename returned by
+    /// [`codename`](#tymethod.codename), if known.
+    fn from_codename(codename: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .cloned()
+            .find(|release| release.codename() == Some(codename))
+    }
+
+    /// Parses a release from its display name, codename or version number,
+    /// ignoring case and an optional leading OS name.
+    ///
+    /// Spaces, hyphens and underscores are treated alike, so `Bionic Beaver`,
+    /// `ubuntu-bionic` and `18.04` all parse as Ubuntu 18.04, and `high_sierra`
+    /// and `macOS 10.13` both parse as macOS 10.13.
+    fn parse(s: &str) -> Result<Self, ParseReleaseError> {
+        let s = normalize(s);
+        let os_name = normalize(Self::OS_NAME);
+        let name = match s.get(os_name.len()..) {
+            Some(rest) if s.starts_with(&os_name) && rest.starts_with(' ') => &rest[1..],
+            _ => &s[..],
+        };
+
+        Self::iter()
+            .find(|release| {
+                normalize(release.name()) == name ||
+                release.codename().map(normalize).as_deref() == Some(name)
+            })
+            .or_else(|| name.parse().ok().and_then(Self::from_version))
+            .ok_or(ParseReleaseError { os_name: Self::OS_NAME })
+    }
+
+    /// Returns the version number of the release.
+    #[inline]
+    fn version(self) -> Version {
+        self.into()
+    }
+
+    /// Returns the display name of the release, such as `Bionic Beaver`.
+    #[inline]
+    fn name(self) -> &'static str {
+        self.into()
+    }
+
+    /// Returns the support status of the release on the date `on`, such as
+    /// [`Date::today()`](../../date/struct.Date.html#method.today).
+    #[inline]
+    fn support_status(self, on: Date) -> SupportStatus {
+        self.lifecycle().status(on)
+    }
+
+    /// Returns the release that came after `self`, if known.
+    fn next(self) -> Option<Self> {
+        let all = Self::all();
+        let index = all.binary_search(&self).ok()?;
+        all.get(index + 1).cloned()
+    }
+
+    /// Returns the release that came before `self`, if known.
+    fn prev(self) -> Option<Self> {
+        let all = Self::all();
+        let index = all.binary_search(&self).ok()?;
+        index.checked_sub(1).map(|index| all[index])
+    }
+
+    /// Returns an iterator over every known release, ordered by version.
+    #[inline]
+    fn iter() -> ReleaseIter<Self> {
+        Self::all().iter().copied()
+    }
+
+    /// Returns an iterator over the known releases within `range`, such as
+    /// `XenialXerus..=BionicBeaver`.
+    fn iter_range<B: RangeBounds<Self>>(range: B) -> ReleaseIter<Self> {
+        subslice(Self::all(), |release| range.contains(release)).iter().copied()
+    }
+
+    /// Returns an iterator over the known releases whose versions are within
+    /// `min..=max`.
+    fn releases_between(min: Version, max: Version) -> ReleaseIter<Self> {
+        subslice(Self::all(), |release| {
+            let version = release.version();
+            min <= version && version <= max
+        }).iter().copied()
+    }
+}
+
+/// Returns the contiguous run of `items` for which `pred` holds.
+fn subslice<T, F: Fn(&T) -> bool>(items: &[T], pred: F) -> &[T] {
+    let start = items.iter().position(&pred).unwrap_or(items.len());
+    let len = items[start..].iter().take_while(|item| pred(item)).count();
+    &items[start..(start + len)]
+}
+
+/// Lower-cases `s` and joins its words with single spaces, treating hyphens
+/// and underscores as spaces.
+fn normalize(s: &str) -> String {
+    s.split(|c: char| c.is_whitespace() || c == '-' || c == '_')
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// An error returned when parsing a release fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseReleaseError {
+    os_name: &'static str,
+}
+
+impl ParseReleaseError {
+    /// Returns the name of the operating system whose release was expected.
+    #[inline]
+    pub fn os_name(&self) -> &'static str {
+        self.os_name
+    }
+}
+
+impl fmt::Display for ParseReleaseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown {} release", self.os_name)
+    }
+}
+
+impl Error for ParseReleaseError {}
+
+/// A release of some operating system.
+///
+/// Release tables in this crate are only as recent as the crate itself. A host
+/// running a newer release is reported as [`Unknown`](#variant.Unknown) with
+/// whatever version and codename it provides, rather than with no release at
+/// all.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Release<R> {
+    /// A release known to this crate.
+    Known(R),
+    /// A release not known to this crate.
+    Unknown(UnknownRelease),
+}
+
+/// A release not known to this crate, as reported by the host.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UnknownRelease {
+    /// The version of the release.
+    pub version: Option<Version>,
+    /// The lower-case codename of the release, such as `noble`.
+    pub codename: Option<String>,
+    /// The display name of the release, such as `Noble Numbat`.
+    pub name: Option<String>,
+    /// The lifecycle of the release, if provided by the host.
+    pub lifecycle: Option<Lifecycle>,
+    /// The end of support reported by the host without the rest of the
+    /// lifecycle, such as the `os-release` `SUPPORT_END` of Fedora.
+    pub support_end: Option<Date>,
+}
+
+impl<R> From<R> for Release<R> {
+    #[inline]
+    fn from(release: R) -> Self {
+        Release::Known(release)
+    }
+}
+
+impl<R> Release<R> {
+    /// Returns `known` if found, or else an unknown release if either `version`
+    /// or `codename` is.
+    #[cfg_attr(
+        not(any(
+            target_os = "linux",
+            target_os = "android",
+            target_os = "macos",
+            target_os = "windows",
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) fn from_parts(
+        known: Option<R>,
+        version: Option<Version>,
+        codename: Option<&str>,
+    ) -> Option<Self> {
+        match (known, version, codename) {
+            (Some(known), _, _) => Some(Release::Known(known)),
+            (None, None, None)  => None,
+            (None, version, codename) => Some(Release::Unknown(UnknownRelease {
+                version,
+                codename: codename.map(str::to_lowercase),
+                ..Default::default()
+            })),
+        }
+    }
+
+    /// Returns the release if it is known to this crate.
+    #[inline]
+    pub fn known(&self) -> Option<&R> {
+        match self {
+            Release::Known(release) => Some(release),
+            Release::Unknown(_) => None,
+        }
+    }
+
+    /// Returns whether the release is known to this crate.
+    #[inline]
+    pub fn is_known(&self) -> bool {
+        self.known().is_some()
+    }
+}
+
+impl<R: KnownRelease> Release<R> {
+    /// Returns the version of the release, if known.
+    #[inline]
+    pub fn version(&self) -> Option<Version> {
+        match self {
+            Release::Known(release) => Some(release.version()),
+            Release::Unknown(release) => release.version,
+        }
+    }
+
+    /// Returns the name of the release, which may be its codename if unknown.
+    #[inline]
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Release::Known(release) => Some(release.name()),
+            Release::Unknown(release) => {
+                release.name.as_deref().or(release.codename.as_deref())
+            },
+        }
+    }
+
+    /// Returns the lower-case codename of the release, if known.
+    #[inline]
+    pub fn codename(&self) -> Option<&str> {
+        match self {
+            Release::Known(release) => release.codename(),
+            Release::Unknown(release) => release.codename.as_deref(),
+        }
+    }
+
+    /// Returns the lifecycle of the release, if known.
+    #[inline]
+    pub fn lifecycle(&self) -> Option<Lifecycle> {
+        match self {
+            Release::Known(release) => Some(release.lifecycle()),
+            Release::Unknown(release) => release.lifecycle,
+        }
+    }
+
+    /// Returns the support status of the release on the date `on`, if its
+    /// lifecycle or the end of its support is known.
+    pub fn support_status(&self, on: Date) -> Option<SupportStatus> {
+        match self {
+            Release::Unknown(UnknownRelease {
+                lifecycle: None,
+                support_end: Some(support_end),
+                ..
+            }) => Some(if on <= *support_end {
+                SupportStatus::Supported
+            } else {
+                SupportStatus::EndOfLife
+            }),
+            release => release.lifecycle().map(|lifecycle| lifecycle.status(on)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use info::os::linux::ubuntu::OsRelease;
+    use info::os::linux::suse;
+
+    fn check_navigation<R: KnownRelease>() {
+        let all = R::all();
+        assert_eq!(all.first(), Some(&R::MIN), "{}", R::OS_NAME);
+        assert_eq!(all.last(), Some(&R::LATEST), "{}", R::OS_NAME);
+        assert_eq!(R::MIN.prev(), None);
+        assert_eq!(R::LATEST.next(), None);
+
+        // SLE 12 service packs came out after SLE 15
+        let dated = R::OS_NAME != suse::OsRelease::OS_NAME;
+        for pair in all.windows(2) {
+            assert!(pair[0] < pair[1]);
+            assert!(pair[0].version() < pair[1].version());
+            if dated {
+                assert!(pair[0].lifecycle().released < pair[1].lifecycle().released);
+            }
+            assert_eq!(pair[0].next(), Some(pair[1]));
+            assert_eq!(pair[1].prev(), Some(pair[0]));
+        }
+        let mut names: Vec<_> = all.iter().map(|release| release.name()).collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), all.len(), "{}", R::OS_NAME);
+
+        for &release in all {
+            let Lifecycle { released, support_end, extended_end } = release.lifecycle();
+            if let Some(end) = support_end {
+                assert!(end > released, "{:?}", release);
+                assert!(extended_end.unwrap_or(end) >= end, "{:?}", release);
+            }
+            assert_eq!(R::from_version(release.version()), Some(release));
+            assert_eq!(R::parse(release.name()), Ok(release));
+            if let Some(codename) = release.codename() {
+                assert_eq!(R::from_codename(codename), Some(release));
+                assert_eq!(R::parse(codename), Ok(release));
+            }
+        }
+    }
+
+    #[test]
+    fn navigation() {
+        use info::os::{android, linux::debian, linux::ubuntu, macos, windows};
+        use info::os::linux::{alpine, amazon, fedora, nixos, rhel};
+
+        check_navigation::<debian::OsRelease>();
+        check_navigation::<ubuntu::OsRelease>();
+        check_navigation::<fedora::OsRelease>();
+        check_navigation::<rhel::OsRelease>();
+        check_navigation::<suse::OsRelease>();
+        check_navigation::<amazon::OsRelease>();
+        check_navigation::<alpine::OsRelease>();
+        check_navigation::<nixos::OsRelease>();
+        check_navigation::<android::OsRelease>();
+        check_navigation::<macos::OsRelease>();
+        check_navigation::<windows::OsRelease>();
+    }
+
+    #[test]
+    fn parse() {
+        use info::os::{linux::debian, macos, windows};
+
+        let ubuntu = [
+            "bionic",
+            "Bionic Beaver",
+            "BIONIC-BEAVER",
+            "ubuntu-bionic",
+            "Ubuntu 18.04",
+            "18.04",
+            "18.04.6",
+        ];
+        for s in &ubuntu {
+            assert_eq!(s.parse(), Ok(OsRelease::BionicBeaver), "{}", s);
+        }
+        assert_eq!("stretch".parse(), Ok(debian::OsRelease::Stretch));
+        assert_eq!("debian 9.13".parse(), Ok(debian::OsRelease::Stretch));
+        assert_eq!("12.5".parse(), Ok(debian::OsRelease::Bookworm));
+        assert_eq!("mojave".parse(), Ok(macos::OsRelease::Mojave));
+        assert_eq!("10.14".parse(), Ok(macos::OsRelease::Mojave));
+        assert_eq!("macOS High Sierra".parse(), Ok(macos::OsRelease::HighSierra));
+        assert_eq!("mountain_lion".parse(), Ok(macos::OsRelease::MountainLion));
+        assert_eq!("Windows 8.1".parse(), Ok(windows::OsRelease::Windows8_1));
+        assert_eq!("6.1".parse(), Ok(windows::OsRelease::Windows7));
+
+        let error = "ubuntu-warty".parse::<OsRelease>().unwrap_err();
+        assert_eq!(error.os_name(), "Ubuntu");
+        assert_eq!(error.to_string(), "unknown Ubuntu release");
+        assert!("ubuntu".parse::<OsRelease>().is_err());
+        assert!("".parse::<OsRelease>().is_err());
+    }
+
+    #[test]
+    fn ranges() {
+        use self::OsRelease::*;
+
+        let releases: Vec<_> = OsRelease::iter_range(XenialXerus..=BionicBeaver)
+            .collect();
+        assert_eq!(releases, [
+            XenialXerus,
+            YakketyYak,
+            ZestyZapus,
+            ArtfulAardvark,
+            BionicBeaver,
+        ]);
+
+        let releases: Vec<_> = OsRelease::iter_range(PluckyPuffin..).collect();
+        assert_eq!(releases, [PluckyPuffin, QuestingQuokka, ResoluteRaccoon]);
+
+        let releases: Vec<_> = OsRelease::iter_range(..HardyHeron).collect();
+        assert_eq!(releases, [FeistyFawn, GutsyGibbon]);
+
+        assert_eq!(OsRelease::iter_range(BionicBeaver..BionicBeaver).count(), 0);
+        assert_eq!(OsRelease::iter_range(..).count(), OsRelease::all().len());
+
+        let releases: Vec<_> = OsRelease::releases_between(
+            Version::new(16, 10, 0),
+            Version::new(18, 0, 0),
+        ).collect();
+        assert_eq!(releases, [YakketyYak, ZestyZapus, ArtfulAardvark]);
+    }
+
+    #[test]
+    fn from_parts() {
+        let bionic = Release::from_parts(
+            Some(OsRelease::BionicBeaver),
+            Some(Version::new(18, 4, 0)),
+            Some("bionic"),
+        ).unwrap();
+        assert_eq!(bionic, Release::Known(OsRelease::BionicBeaver));
+        assert_eq!(bionic.name(), Some("Bionic Beaver"));
+
+        let noble = Release::<OsRelease>::from_parts(
+            None,
+            Some(Version::new(24, 4, 0)),
+            Some("Noble"),
+        ).unwrap();
+        assert!(!noble.is_known());
+        assert_eq!(noble.version(), Some(Version::new(24, 4, 0)));
+        assert_eq!(noble.name(), Some("noble"));
+
+        assert_eq!(Release::<OsRelease>::from_parts(None, None, None), None);
+    }
+}
@@ -16,8 +16,16 @@ cfg_if! {
     }
 }
 
+#[cfg(unix)]
+extern crate libc;
+
+#[macro_use]
+mod macros;
+
+pub mod date;
 pub mod info;
 pub mod version;
 
+pub use date::Date;
 pub use info::{OsInfo, OsMeta};
-pub use version::OsVersion;
+pub use version::{KernelVersion, OsVersion};
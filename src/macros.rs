@@ -0,0 +1,166 @@
+//! Macros used throughout the crate.
+
+/// Defines an `OsRelease` enum and its [`KnownRelease`] implementation from a
+/// single table of releases, ordered by version.
+///
+/// Each release lists its version as a `(major, minor)` pattern, where a minor
+/// version of `_` matches any point release; its display name; its lower-case
+/// codename or `-`; and its release date, end of standard support and end of
+/// extended support, each a `(year, month, day)` or `-` if there is none.
+///
+/// Fields specific to an OS are declared in a `struct Data { .. }` after the
+/// table and given after these for every release. They are accessed with
+/// `self.data()`.
+///
+/// [`KnownRelease`]: info/release/trait.KnownRelease.html
+macro_rules! releases {
+    (
+        $(#[$attr:meta])*
+        pub enum $name:ident: $os_name:tt {
+            $(
+                $(#[$variant_attr:meta])*
+                $variant:ident {
+                    version: ($major:tt, $minor:tt),
+                    name: $display:expr,
+                    codename: $codename:tt,
+                    lifecycle: [$released:tt, $support_end:tt, $extended_end:tt],
+                    $($extra_field:ident: $extra_value:expr,)*
+                }
+            )+
+        }
+        $(struct Data { $($field:ident: $ty:ty,)* })?
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[non_exhaustive]
+        pub enum $name {
+            $(
+                $(#[$variant_attr])*
+                $variant,
+            )+
+        }
+
+        /// A row of the release table.
+        struct Data {
+            version: ::version::Version,
+            name: &'static str,
+            codename: Option<&'static str>,
+            lifecycle: ::info::Lifecycle,
+            $($($field: $ty,)*)?
+        }
+
+        /// The release table, indexed by `OsRelease as usize`.
+        // Dates pad months and days with zeros to keep the columns aligned
+        #[allow(clippy::zero_prefixed_literal)]
+        const DATA: &[Data] = &[
+            $(
+                Data {
+                    version: ::version::Version {
+                        major: $major,
+                        minor: releases!(@minor $minor),
+                        patch: 0,
+                    },
+                    name: $display,
+                    codename: releases!(@option $codename),
+                    lifecycle: ::info::Lifecycle {
+                        released: releases!(@date $released),
+                        support_end: releases!(@option_date $support_end),
+                        extended_end: releases!(@option_date $extended_end),
+                    },
+                    $($extra_field: $extra_value,)*
+                },
+            )+
+        ];
+
+        /// Every known release, ordered by version.
+        const ALL: &[$name] = &[$($name::$variant),+];
+
+        impl $name {
+            /// The minimum supported OS release.
+            pub const MIN: $name = ALL[0];
+
+            /// The OS release with the highest version.
+            pub const LATEST: $name = ALL[ALL.len() - 1];
+
+            /// Returns the corresponding release for the version number.
+            #[inline]
+            pub fn new<V: Into<::version::Version>>(version: V) -> Option<Self> {
+                <Self as ::info::KnownRelease>::from_version(version.into())
+            }
+
+            /// Returns the row of the release table for `self`.
+            #[inline]
+            #[allow(dead_code)]
+            fn data(self) -> &'static Data {
+                &DATA[self as usize]
+            }
+        }
+
+        impl<'a> From<$name> for &'a str {
+            #[inline]
+            fn from(release: $name) -> Self {
+                release.data().name
+            }
+        }
+
+        impl From<$name> for ::version::Version {
+            #[inline]
+            fn from(release: $name) -> Self {
+                release.data().version
+            }
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = ::info::ParseReleaseError;
+
+            #[inline]
+            fn from_str(s: &str) -> Result<Self, ::info::ParseReleaseError> {
+                <Self as ::info::KnownRelease>::parse(s)
+            }
+        }
+
+        impl ::info::KnownRelease for $name {
+            const OS_NAME: &'static str = $os_name;
+
+            const MIN: $name = $name::MIN;
+
+            const LATEST: $name = $name::LATEST;
+
+            #[inline]
+            fn all() -> &'static [Self] {
+                ALL
+            }
+
+            #[allow(clippy::zero_prefixed_literal)]
+            fn from_version(version: ::version::Version) -> Option<Self> {
+                match (version.major, version.minor) {
+                    $(($major, $minor) => Some($name::$variant),)+
+                    _ => None,
+                }
+            }
+
+            #[inline]
+            fn codename(self) -> Option<&'static str> {
+                self.data().codename
+            }
+
+            #[inline]
+            fn lifecycle(self) -> ::info::Lifecycle {
+                self.data().lifecycle
+            }
+        }
+    };
+
+    (@minor _) => { 0 };
+    (@minor $minor:tt) => { $minor };
+
+    (@option -) => { None };
+    (@option $value:tt) => { Some($value) };
+
+    (@date ($year:expr, $month:expr, $day:expr)) => {
+        ::date::Date { year: $year, month: $month, day: $day }
+    };
+
+    (@option_date -) => { None };
+    (@option_date $date:tt) => { Some(releases!(@date $date)) };
+}
@@ -1,5 +1,6 @@
 //! Simple version information.
 
+use std::cmp::Ordering;
 use std::fmt;
 use std::num::ParseIntError;
 use std::str::FromStr;
@@ -85,14 +86,14 @@ impl FromStr for Version {
             return Err(ExtraInput);
         }
 
-        vers.major = major.parse().map_err(|e| MajorInt(e))?;
+        vers.major = major.parse().map_err(MajorInt)?;
 
         if let Some(minor) = minor {
-            vers.minor = minor.parse().map_err(|e| MinorInt(e))?;
+            vers.minor = minor.parse().map_err(MinorInt)?;
         }
 
         if let Some(patch) = patch {
-            vers.patch = patch.parse().map_err(|e| PatchInt(e))?;
+            vers.patch = patch.parse().map_err(PatchInt)?;
         }
 
         Ok(vers)
@@ -210,12 +211,29 @@ impl OsVersion {
         }
     }
 
-    #[cfg(target_os = "linux")]
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn _get() -> Option<Self> {
+        ::info::os::get_version()
+    }
+
+    #[cfg(not(any(
+        target_os = "macos",
+        target_os = "windows",
+        target_os = "linux",
+        target_os = "android",
+    )))]
     fn _get() -> Option<Self> {
         None
     }
 
     /// Queries the current operating system version.
+    ///
+    /// On Linux, this is the distribution's version as found in
+    /// `/etc/os-release`, `/etc/lsb-release` or a distribution-specific file
+    /// such as `/etc/debian_version`.
+    ///
+    /// Returns `None` on platforms that are not supported by this crate, and
+    /// on distributions without a version, such as rolling releases.
     pub fn get() -> Option<Self> {
         Self::_get()
     }
@@ -233,6 +251,227 @@ impl OsVersion {
     }
 }
 
+/// A Linux kernel release, as reported by `uname -r`.
+///
+/// Kernel releases extend a `MAJOR.MINOR.PATCH` version with distribution
+/// specifics, such as `4.15.0-45-generic` on Ubuntu, `5.10.0-21-amd64` on
+/// Debian or `6.1.0-rc3+` for a locally built release candidate.
+///
+/// Comparing against a [`Version`](struct.Version.html) only considers the
+/// upstream version, where a release candidate comes before its release.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct KernelVersion {
+    /// The upstream `MAJOR.MINOR.PATCH` version.
+    pub version: Version,
+    /// A fourth version component, such as `1` in
+    /// `5.15.90.1-microsoft-standard-WSL2`.
+    pub revision: Option<u64>,
+    /// The pre-release marker, such as `rc3`.
+    pub pre: Option<String>,
+    /// The ABI number of a distribution kernel, such as `45` in
+    /// `4.15.0-45-generic`.
+    pub abi: Option<u64>,
+    /// The kernel flavour, such as `generic`, `lowlatency`, `aws` or `amd64`.
+    pub flavour: Option<String>,
+    /// Whether the flavour follows the ABI number after a `.` rather than a
+    /// `-`, as in the `1160.el7.x86_64` of RHEL.
+    pub dotted_flavour: bool,
+    /// The local version suffix following a `+`, which is empty for a kernel
+    /// built from a modified source tree.
+    pub local: Option<String>,
+}
+
+impl fmt::Display for KernelVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.version)?;
+        if let Some(revision) = self.revision {
+            write!(f, ".{}", revision)?;
+        }
+        if let Some(pre) = &self.pre {
+            write!(f, "-{}", pre)?;
+        }
+        if let Some(abi) = self.abi {
+            write!(f, "-{}", abi)?;
+        }
+        if let Some(flavour) = &self.flavour {
+            let separator = match (self.abi, self.dotted_flavour) {
+                (Some(_), true) => '.',
+                _ => '-',
+            };
+            write!(f, "{}{}", separator, flavour)?;
+        }
+        if let Some(local) = &self.local {
+            write!(f, "+{}", local)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for KernelVersion {
+    type Err = ParseVersionError;
+
+    fn from_str(s: &str) -> Result<KernelVersion, ParseVersionError> {
+        let s = s.trim();
+        let (s, local) = match s.find('+') {
+            Some(i) => (&s[..i], Some(s[(i + 1)..].to_owned())),
+            None => (s, None),
+        };
+
+        let mut parts = s.split('-');
+        let numbers = parts.next().unwrap_or_default();
+        let (numbers, revision) = match numbers.match_indices('.').nth(2) {
+            Some((i, _)) => (&numbers[..i], Some(&numbers[(i + 1)..])),
+            None => (numbers, None),
+        };
+
+        let mut kernel = KernelVersion {
+            version: numbers.parse()?,
+            local,
+            ..Default::default()
+        };
+        if let Some(revision) = revision {
+            let revision = revision.parse();
+            kernel.revision = Some(revision.map_err(|_| ParseVersionError::ExtraInput)?);
+        }
+
+        let mut rest: Vec<&str> = parts.filter(|part| !part.is_empty()).collect();
+        if rest.first().cloned().filter(|part| is_pre_release(part)).is_some() {
+            kernel.pre = Some(rest.remove(0).to_owned());
+        }
+        if let (None, Some(&first)) = (kernel.abi, rest.first()) {
+            let end = first
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(first.len());
+            if end > 0 {
+                kernel.abi = first[..end].parse().ok();
+
+                // Keep anything attached to the ABI, as in `1160.el7.x86_64`
+                kernel.dotted_flavour = first[end..].starts_with('.');
+                match first[end..].trim_start_matches('.') {
+                    ""       => { rest.remove(0); },
+                    leftover => rest[0] = leftover,
+                }
+            }
+        }
+        if !rest.is_empty() {
+            kernel.flavour = Some(rest.join("-"));
+        }
+
+        Ok(kernel)
+    }
+}
+
+impl Ord for KernelVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.version.cmp(&other.version)
+            .then_with(|| self.revision.cmp(&other.revision))
+            .then_with(|| cmp_pre_release(&self.pre, &other.pre))
+            .then_with(|| self.abi.cmp(&other.abi))
+            .then_with(|| self.flavour.cmp(&other.flavour))
+            .then_with(|| self.local.cmp(&other.local))
+            .then_with(|| self.dotted_flavour.cmp(&other.dotted_flavour))
+    }
+}
+
+impl PartialOrd for KernelVersion {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq<Version> for KernelVersion {
+    #[inline]
+    fn eq(&self, other: &Version) -> bool {
+        self.pre.is_none() && self.version == *other
+    }
+}
+
+impl PartialEq<KernelVersion> for Version {
+    #[inline]
+    fn eq(&self, other: &KernelVersion) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<Version> for KernelVersion {
+    fn partial_cmp(&self, other: &Version) -> Option<Ordering> {
+        let pre = match self.pre {
+            Some(_) => Ordering::Less,
+            None => Ordering::Equal,
+        };
+        Some(self.version.cmp(other).then(pre))
+    }
+}
+
+impl PartialOrd<KernelVersion> for Version {
+    #[inline]
+    fn partial_cmp(&self, other: &KernelVersion) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+impl From<Version> for KernelVersion {
+    #[inline]
+    fn from(version: Version) -> Self {
+        KernelVersion { version, ..Default::default() }
+    }
+}
+
+impl KernelVersion {
+    #[cfg(unix)]
+    fn _get() -> Option<Self> {
+        use std::ffi::CStr;
+        use std::mem;
+
+        let release = unsafe {
+            let mut name = mem::zeroed::<libc::utsname>();
+            if libc::uname(&mut name) != 0 {
+                return None;
+            }
+            CStr::from_ptr(name.release.as_ptr()).to_string_lossy().into_owned()
+        };
+        release.parse().ok()
+    }
+
+    #[cfg(not(unix))]
+    fn _get() -> Option<Self> {
+        None
+    }
+
+    /// Queries the version of the running kernel with `uname(2)`.
+    ///
+    /// Returns `None` on platforms without `uname(2)`, such as Windows.
+    pub fn get() -> Option<Self> {
+        Self::_get()
+    }
+
+    /// Parses a kernel release string, such as `4.15.0-45-generic`.
+    #[inline]
+    pub fn parse(release: &str) -> Result<KernelVersion, ParseVersionError> {
+        release.parse()
+    }
+}
+
+/// Returns whether `part` of a kernel release is a marker like `rc3`.
+fn is_pre_release(part: &str) -> bool {
+    part.len() > 2 &&
+    part.starts_with("rc") &&
+    part[2..].bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Orders releases so that a pre-release comes before its release, and `rc10`
+/// comes after `rc9`.
+fn cmp_pre_release(a: &Option<String>, b: &Option<String>) -> Ordering {
+    let number = |pre: &str| pre[2..].parse::<u64>().unwrap_or(0);
+    match (a, b) {
+        (None,    None)    => Ordering::Equal,
+        (None,    Some(_)) => Ordering::Greater,
+        (Some(_), None)    => Ordering::Less,
+        (Some(a), Some(b)) => number(a).cmp(&number(b)).then_with(|| a.cmp(b)),
+    }
+}
+
 /// An error returned when parsing a version string fails.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ParseVersionError {
@@ -290,6 +529,72 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_kernel() {
+        let kernel = |
+            version: VersionTriple,
+            pre: Option<&str>,
+            abi: Option<u64>,
+            flavour: Option<&str>,
+            local: Option<&str>,
+        | KernelVersion {
+            version: version.into(),
+            pre: pre.map(Into::into),
+            abi,
+            flavour: flavour.map(Into::into),
+            local: local.map(Into::into),
+            ..Default::default()
+        };
+        let cases = vec![
+            ("4.15.0-45-generic",      kernel((4, 15, 0), None, Some(45), Some("generic"), None)),
+            ("5.10.0-21-amd64",        kernel((5, 10, 0), None, Some(21), Some("amd64"), None)),
+            ("4.15.0-1031-aws",        kernel((4, 15, 0), None, Some(1031), Some("aws"), None)),
+            ("6.1.0-rc3+",             kernel((6, 1, 0), Some("rc3"), None, None, Some(""))),
+            ("4.19.0",                 kernel((4, 19, 0), None, None, None, None)),
+            ("3.10.0-1160.el7.x86_64", KernelVersion {
+                dotted_flavour: true,
+                ..kernel((3, 10, 0), None, Some(1160), Some("el7.x86_64"), None)
+            }),
+            ("5.15.90.1-microsoft-standard-WSL2", KernelVersion {
+                revision: Some(1),
+                ..kernel((5, 15, 90), None, None, Some("microsoft-standard-WSL2"), None)
+            }),
+        ];
+        for (string, expected) in cases {
+            assert_eq!(KernelVersion::parse(string), Ok(expected));
+        }
+
+        let strings = [
+            "4.15.0-45-generic",
+            "6.1.0-rc3+",
+            "4.19.0",
+            "3.10.0-1160.el7.x86_64",
+            "5.15.90.1-microsoft-standard-WSL2",
+            "5.14.21-150500.55.39-default",
+        ];
+        for &string in &strings {
+            assert_eq!(KernelVersion::parse(string).unwrap().to_string(), string);
+        }
+
+        for &string in &["", "generic", "4.x.0-45"] {
+            KernelVersion::parse(string).expect_err(string);
+        }
+    }
+
+    #[test]
+    fn cmp_kernel() {
+        let kernel = |s: &str| KernelVersion::parse(s).unwrap();
+
+        assert!(kernel("6.1.0-rc3") < kernel("6.1.0"));
+        assert!(kernel("6.1.0-rc9") < kernel("6.1.0-rc10"));
+        assert!(kernel("4.15.0-45-generic") < kernel("4.15.0-46-generic"));
+
+        assert!(kernel("4.15.0-45-generic") == Version::new(4, 15, 0));
+        assert!(kernel("4.15.0-45-generic") >= Version::new(4, 15, 0));
+        assert!(kernel("4.15.0-rc1") < Version::new(4, 15, 0));
+        assert!(Version::new(4, 18, 0) > kernel("4.15.0-45-generic"));
+    }
+
     #[test]
     fn parse_failure() {
         let strings: &[&str] = &[
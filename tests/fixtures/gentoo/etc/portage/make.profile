@@ -0,0 +1 @@
+../../var/db/repos/gentoo/profiles/default/linux/amd64/23.0/systemd
\ No newline at end of file